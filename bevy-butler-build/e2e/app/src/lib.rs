@@ -0,0 +1,65 @@
+//! A library that adds entries to its own plugins, and to the plugin of another crate.
+
+use bevy::prelude::*;
+use bevy_butler::*;
+use butler_e2e_core::Log;
+// Links the entries of a crate that nothing else refers to
+use butler_e2e_plain as _;
+
+bevy_butler::include_butler_entries!(butler_e2e_core);
+
+#[butler_plugin_group]
+pub struct GamePlugins;
+
+#[butler_plugin]
+#[add_plugin(to_group = GamePlugins)]
+pub struct AppPlugin;
+
+#[add_plugin(to_group = GamePlugins, init = CorePlugin)]
+use butler_e2e_core::CorePlugin;
+
+#[add_system(plugin = AppPlugin, schedule = Update)]
+fn app_system(mut log: ResMut<Log>) {
+    log.0.push("app");
+}
+
+#[add_system(plugin = CorePlugin, schedule = Update)]
+fn extends_core(mut log: ResMut<Log>) {
+    log.0.push("extends core");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use butler_e2e_core::physics::Gravity;
+
+    #[test]
+    fn entries() {
+        let mut app = App::new();
+        app.add_plugins(GamePlugins);
+        app.update();
+
+        assert_eq!(app.world().resource::<Gravity>().0, 9.8);
+        let mut log = app.world_mut().remove_resource::<Log>().unwrap().0;
+        log.sort();
+        assert_eq!(
+            log,
+            [
+                "app",
+                "cfg_attr",
+                "compact",
+                "core",
+                "extends core",
+                "generic",
+                "multi line",
+                "observer",
+                "plain"
+            ]
+        );
+
+        let mut names = app.world_mut().query::<&Name>();
+        assert!(names
+            .iter(app.world())
+            .any(|name| name.as_str() == "Marker  observer, with   spaces"));
+    }
+}
@@ -0,0 +1,14 @@
+//! Entries submitted to a plugin that was already built can't be registered anymore.
+
+use bevy::prelude::*;
+use butler_e2e_app::AppPlugin;
+use butler_e2e_core::CorePlugin;
+
+#[test]
+#[should_panic(expected = "was submitted after the plugin it's added to was built")]
+fn app_plugin_after_core_plugin() {
+    let mut app = App::new();
+    app.add_plugins(CorePlugin);
+    // Submits the table of the app crate, which adds `extends_core` to `CorePlugin`
+    app.add_plugins(AppPlugin);
+}
@@ -0,0 +1,20 @@
+//! Plugins built after every table was submitted get the entries that other crates add to them.
+
+use bevy::prelude::*;
+use butler_e2e_app::AppPlugin;
+use butler_e2e_core::{CorePlugin, Log};
+
+#[test]
+fn core_plugin_first() {
+    bevy_butler::submit_butler_entries!(butler_e2e_app);
+
+    let mut app = App::new();
+    app.add_plugins(CorePlugin);
+    app.add_plugins(AppPlugin);
+    app.update();
+
+    let log = &app.world().resource::<Log>().0;
+    assert!(log.contains(&"core"));
+    assert!(log.contains(&"extends core"));
+    assert!(log.contains(&"app"));
+}
@@ -0,0 +1,52 @@
+//! The build script has to derive the same entry names as the butler macros,
+//! however their arguments are written.
+
+use core::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::{CorePlugin, Log};
+
+#[add_system(
+    plugin = CorePlugin,
+    schedule = Update,
+    run_if = |log: Option<Res<Log>>| log.is_some(),
+)]
+fn multi_line(mut log: ResMut<Log>) {
+    log.0.push("multi line");
+}
+
+#[add_system(plugin=crate::CorePlugin,schedule=Update,after=multi_line)]
+fn compact(mut log: ResMut<Log>) {
+    log.0.push("compact");
+}
+
+#[add_system(generics = <PhantomData<Vec<u8>>>, plugin = CorePlugin, schedule = Update)]
+fn generic<T: FromWorld + Send + 'static>(_: Local<T>, mut log: ResMut<Log>) {
+    log.0.push("generic");
+}
+
+#[cfg_attr(all(), add_system(plugin = CorePlugin, schedule = Update))]
+fn cfg_attr_enabled(mut log: ResMut<Log>) {
+    log.0.push("cfg_attr");
+}
+
+#[cfg_attr(any(), add_system(plugin = CorePlugin, schedule = Update))]
+#[allow(dead_code)]
+fn cfg_attr_disabled(mut log: ResMut<Log>) {
+    log.0.push("disabled");
+}
+
+#[derive(Component)]
+pub struct Marker;
+
+#[add_observer(plugin = CorePlugin, name = "Marker  observer, with   spaces")]
+fn marker_observer(_: Trigger<OnAdd, Marker>, mut log: ResMut<Log>) {
+    log.0.push("observer");
+}
+
+#[add_system(plugin = CorePlugin, schedule = Startup)]
+fn spawn_marker(mut commands: Commands) {
+    commands.spawn(Marker);
+}
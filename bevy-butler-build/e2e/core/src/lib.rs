@@ -0,0 +1,39 @@
+//! A library whose entries are registered through the table generated by its build script.
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_butler::*;
+//!
+//! // Doctests aren't part of the library, so they use the default backend
+//! #[butler_plugin]
+//! struct DocPlugin;
+//!
+//! #[derive(Resource)]
+//! #[insert_resource(plugin = DocPlugin, init = Ran)]
+//! struct Ran;
+//!
+//! let mut app = App::new();
+//! app.add_plugins(DocPlugin);
+//! assert!(app.world().contains_resource::<Ran>());
+//! ```
+
+use bevy::prelude::*;
+use bevy_butler::*;
+
+bevy_butler::include_butler_entries!();
+
+mod formats;
+pub mod physics;
+
+#[butler_plugin]
+pub struct CorePlugin;
+
+/// The names of the systems that ran.
+#[derive(Resource, Default, Debug)]
+#[insert_resource(plugin = CorePlugin)]
+pub struct Log(pub Vec<&'static str>);
+
+#[add_system(plugin = CorePlugin, schedule = Update)]
+fn core_system(mut log: ResMut<Log>) {
+    log.0.push("core");
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[derive(Resource)]
+#[insert_resource(plugin = crate::CorePlugin, init = Gravity(9.8))]
+pub struct Gravity(pub f32);
@@ -0,0 +1,31 @@
+//! Integration tests are separate crates, so they use the default backend,
+//! even though the library registers its entries through `bevy-butler-build`.
+
+use bevy::prelude::*;
+use bevy_butler::*;
+use butler_e2e_core::{CorePlugin, Log};
+
+#[butler_plugin]
+struct TestPlugin;
+
+#[add_system(plugin = TestPlugin, schedule = Update)]
+fn test_system(mut log: ResMut<Log>) {
+    log.0.push("test");
+}
+
+#[add_system(plugin = CorePlugin, schedule = Update)]
+fn extends_core(mut log: ResMut<Log>) {
+    log.0.push("extends core");
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((CorePlugin, TestPlugin));
+    app.update();
+
+    let log = &app.world().resource::<Log>().0;
+    for system in ["core", "extends core", "test"] {
+        assert!(log.contains(&system), "{system} didn't run");
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    bevy_butler_build::generate();
+}
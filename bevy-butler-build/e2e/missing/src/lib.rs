@@ -0,0 +1,24 @@
+//! Entries that the build script can't find, which have to fail to compile
+//! instead of never being registered.
+
+use bevy::prelude::*;
+use bevy_butler::*;
+
+bevy_butler::include_butler_entries!();
+
+#[butler_plugin]
+pub struct MissingPlugin;
+
+macro_rules! system {
+    ($name:ident) => {
+        #[add_system(plugin = MissingPlugin, schedule = Update)]
+        fn $name() {}
+    };
+}
+
+system!(from_macro);
+
+pub fn setup() {
+    #[add_system(plugin = MissingPlugin, schedule = Update)]
+    fn in_function() {}
+}
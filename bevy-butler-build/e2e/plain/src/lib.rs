@@ -0,0 +1,10 @@
+//! A library without a build script, which keeps using the default backend.
+
+use bevy::prelude::*;
+use bevy_butler::*;
+use butler_e2e_core::{CorePlugin, Log};
+
+#[add_system(plugin = CorePlugin, schedule = Update)]
+fn plain_system(mut log: ResMut<Log>) {
+    log.0.push("plain");
+}
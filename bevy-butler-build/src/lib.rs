@@ -0,0 +1,502 @@
+//! Build script helper for the `codegen` backend of [bevy-butler](https://docs.rs/bevy-butler).
+//!
+//! Scans the sources of a crate for butler macros, and generates a table that registers every
+//! entry explicitly, instead of relying on linker sections or static constructors.
+//!
+//! ```rust,ignore
+//! // build.rs
+//! fn main() {
+//!     bevy_butler_build::generate();
+//! }
+//! ```
+//!
+//! The generated table is included at the root of the crate with `bevy_butler::include_butler_entries!()`,
+//! and submitted with `bevy_butler::submit_butler_entries!()` before the app adds any plugins.
+//!
+//! Only the crate that was scanned registers its entries through the table. Other targets of the
+//! package, like integration tests and examples, and crates without this build script keep using
+//! the default backend of bevy-butler.
+//!
+//! # Limitations
+//! Entries are found by parsing the source files of the crate, starting from its root, without
+//! expanding any macros. Annotations have to be written on items in modules that are visible from
+//! the crate root, either directly or inside `#[cfg_attr(...)]`. Annotations generated by other
+//! macros, or placed on items inside function bodies, can't be found. The butler macros check
+//! their entries against the table, so these fail to compile instead of never being registered.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, Attribute, Item, Meta, Token, UseTree, Visibility};
+
+/// The attribute macros that add an entry to a butler plugin or plugin group.
+const ENTRY_MACROS: &[&str] = &[
+    "add_system",
+    "add_observer",
+    "insert_resource",
+    "add_event",
+    "register_type",
+    "register_component",
+    "spawn",
+    "add_plugin",
+    "add_plugin_group",
+    "insert_state",
+    "init_asset",
+    "register_asset_loader",
+    "register_asset_processor",
+    "register_asset_reflect",
+];
+
+/// The name of the file generated in `OUT_DIR`.
+const OUTPUT_FILE: &str = "bevy_butler_entries.rs";
+
+/// Generates the table for the current crate with the default [`Builder`].
+///
+/// # Panics
+/// Panics if the sources can't be read or parsed, or if an entry can't be reached from the
+/// crate root.
+pub fn generate() {
+    if let Err(e) = Builder::new().generate() {
+        panic!("bevy-butler-build: {e}");
+    }
+}
+
+/// The environment variable that tells the butler macros which crate the table was generated for.
+const TARGET_VAR: &str = "BEVY_BUTLER_CODEGEN";
+
+/// Configures how the sources of a crate are scanned.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    root: Option<PathBuf>,
+    crate_name: Option<String>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the root file of the crate. Defaults to `src/lib.rs` if it exists, or `src/main.rs`.
+    ///
+    /// Relative paths are resolved from `CARGO_MANIFEST_DIR`.
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Sets the name of the crate that is scanned, if it's different from the package name.
+    ///
+    /// Binaries in `src/bin` are named after their file by default.
+    pub fn crate_name(mut self, name: impl Into<String>) -> Self {
+        self.crate_name = Some(name.into());
+        self
+    }
+
+    /// Writes the table to `OUT_DIR`, and tells cargo to rerun the build script when any
+    /// of the scanned files change.
+    pub fn generate(self) -> Result<(), Error> {
+        let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is only set for build scripts");
+        let target = self.target();
+        let table = self.scan()?;
+
+        println!("cargo:rustc-env={TARGET_VAR}={target}");
+        for file in &table.files {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+
+        let path = Path::new(&out_dir).join(OUTPUT_FILE);
+        fs::write(&path, table.to_source()).map_err(|e| Error::Io(path, e))
+    }
+
+    /// Scans the crate, and returns the source of the table without writing it.
+    pub fn to_source(self) -> Result<String, Error> {
+        Ok(self.scan()?.to_source())
+    }
+
+    fn root_file(&self) -> PathBuf {
+        let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        match &self.root {
+            Some(root) => manifest_dir.join(root),
+            None => {
+                let lib = manifest_dir.join("src/lib.rs");
+                match lib.exists() {
+                    true => lib,
+                    false => manifest_dir.join("src/main.rs"),
+                }
+            }
+        }
+    }
+
+    /// Identifies the scanned crate as `lib:<name>` or `bin:<name>`, which the butler macros
+    /// compare with the crate that they are expanded in.
+    fn target(&self) -> String {
+        let root = self.root_file();
+        let components: Vec<_> = root
+            .with_extension("")
+            .components()
+            .rev()
+            .take(3)
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let components: Vec<_> = components.iter().map(String::as_str).collect();
+
+        // `src/bin/<name>.rs` and `src/bin/<name>/main.rs` are named after their file
+        let (kind, bin_name) = match components[..] {
+            ["main", name, "bin", ..] | [name, "bin", ..] => ("bin", Some(name)),
+            ["main", ..] => ("bin", None),
+            _ => ("lib", None),
+        };
+        let name = match (&self.crate_name, bin_name) {
+            (Some(name), _) => name.clone(),
+            (None, Some(name)) => name.to_string(),
+            (None, None) => env::var("CARGO_PKG_NAME").unwrap_or_default(),
+        };
+        format!("{kind}:{}", name.replace('-', "_"))
+    }
+
+    fn scan(self) -> Result<Table, Error> {
+        let root = self.root_file();
+
+        let mut table = Table::default();
+        let dir = root.parent().map(Path::to_path_buf).unwrap_or_default();
+        let module = Module {
+            path: Vec::new(),
+            file_dir: dir.clone(),
+            child_dir: dir,
+            cfgs: Vec::new(),
+        };
+        table.scan_file(&root, &module)?;
+        Ok(table)
+    }
+}
+
+/// An error while generating the table.
+#[derive(Debug)]
+pub enum Error {
+    /// A source file couldn't be read, or the table couldn't be written.
+    Io(PathBuf, io::Error),
+    /// A source file couldn't be parsed.
+    Parse(PathBuf, syn::Error),
+    /// A module with butler entries can't be named from the crate root, because it's private.
+    PrivateModule(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            Error::Parse(path, e) => write!(f, "{}: {e}", path.display()),
+            Error::PrivateModule(path) => write!(
+                f,
+                "`{path}` contains butler entries, but isn't visible from the crate root. \
+                Make it `pub(crate)` to use it with the codegen backend"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The module that is being scanned.
+struct Module {
+    path: Vec<String>,
+    /// The directory that `#[path]` attributes are relative to.
+    file_dir: PathBuf,
+    /// The directory that the files of child modules are in.
+    child_dir: PathBuf,
+    /// The `#[cfg]` attributes of this module and its parents.
+    cfgs: Vec<String>,
+}
+
+impl Module {
+    fn child(
+        &self,
+        name: String,
+        file_dir: PathBuf,
+        child_dir: PathBuf,
+        cfgs: Vec<String>,
+    ) -> Self {
+        let mut path = self.path.clone();
+        path.push(name);
+        Self {
+            path,
+            file_dir,
+            child_dir,
+            cfgs,
+        }
+    }
+}
+
+/// A static generated by a butler macro.
+struct Entry {
+    path: String,
+    cfgs: Vec<String>,
+}
+
+#[derive(Default)]
+struct Table {
+    entries: Vec<Entry>,
+    files: Vec<PathBuf>,
+}
+
+impl Table {
+    fn scan_file(&mut self, file: &Path, module: &Module) -> Result<(), Error> {
+        let source = fs::read_to_string(file).map_err(|e| Error::Io(file.to_path_buf(), e))?;
+        let parsed = syn::parse_file(&source).map_err(|e| Error::Parse(file.to_path_buf(), e))?;
+        self.files.push(file.to_path_buf());
+
+        self.scan_items(&parsed.items, module)
+    }
+
+    fn scan_items(&mut self, items: &[Item], module: &Module) -> Result<(), Error> {
+        for item in items {
+            let attrs = item_attrs(item);
+            let mut cfgs = module.cfgs.clone();
+            cfgs.extend(cfg_attrs(attrs));
+
+            match item {
+                Item::Mod(item_mod) => {
+                    let name = item_mod.ident.to_string();
+                    let path_attr = path_attr(attrs);
+
+                    match &item_mod.content {
+                        Some((_, items)) => {
+                            let dir = module.child_dir.join(&name);
+                            let child = module.child(name, dir.clone(), dir, cfgs);
+                            let count = self.entries.len();
+                            self.scan_items(items, &child)?;
+                            check_visibility(&item_mod.vis, &child, count != self.entries.len())?;
+                        }
+                        None => {
+                            let file = match &path_attr {
+                                Some(path) => module.file_dir.join(path),
+                                None => {
+                                    let file = module.child_dir.join(format!("{name}.rs"));
+                                    match file.exists() {
+                                        true => file,
+                                        false => module.child_dir.join(&name).join("mod.rs"),
+                                    }
+                                }
+                            };
+                            let file_dir = file.parent().map(Path::to_path_buf).unwrap_or_default();
+
+                            // `#[path]` files and `mod.rs` files own their directory
+                            let owns_dir = path_attr.is_some()
+                                || file.file_name().is_some_and(|f| f == "mod.rs");
+                            let child_dir = match owns_dir {
+                                true => file_dir.clone(),
+                                false => module.child_dir.join(&name),
+                            };
+                            let child = module.child(name, file_dir, child_dir, cfgs);
+                            let count = self.entries.len();
+                            self.scan_file(&file, &child)?;
+                            check_visibility(&item_mod.vis, &child, count != self.entries.len())?;
+                        }
+                    }
+                }
+                Item::Macro(item_macro) if is_macro(&item_macro.mac.path, "butler_chain") => {
+                    self.push(module, &cfgs, "butler_chain", "", &item_macro.mac.tokens);
+                }
+                item => {
+                    let Some(name) = item_name(item) else {
+                        continue;
+                    };
+                    for (attr_cfgs, meta) in entry_attrs(attrs) {
+                        let Some(macro_name) = ENTRY_MACROS
+                            .iter()
+                            .find(|macro_name| is_macro(meta.path(), macro_name))
+                        else {
+                            continue;
+                        };
+                        let args = match &meta {
+                            Meta::List(list) => list.tokens.clone(),
+                            Meta::Path(_) => TokenStream::new(),
+                            Meta::NameValue(_) => continue,
+                        };
+                        let mut cfgs = cfgs.clone();
+                        cfgs.extend(attr_cfgs);
+                        self.push(module, &cfgs, macro_name, &name, &args);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push(
+        &mut self,
+        module: &Module,
+        cfgs: &[String],
+        macro_name: &str,
+        item: &str,
+        args: &TokenStream,
+    ) {
+        let mut path = module.path.clone();
+        path.push(static_name(macro_name, item, args));
+
+        self.entries.push(Entry {
+            path: path.join("::"),
+            cfgs: cfgs.to_vec(),
+        });
+    }
+
+    fn to_source(&self) -> String {
+        // The butler macros check that their entries are listed here, since the ones that the
+        // scan missed would silently never be registered otherwise
+        let mut source = String::from(
+            "// @generated by bevy-butler-build. Do not edit.\n\n\
+            #[doc(hidden)]\n\
+            #[allow(dead_code)]\n\
+            pub(crate) const __BUTLER_CODEGEN_ENTRIES: &[&str] = &[\n",
+        );
+        for entry in &self.entries {
+            source += &format!("    \"{}\",\n", entry.path);
+        }
+        source += "];\n\n\
+            #[allow(unused_variables)]\n\
+            fn __butler_codegen_table(entries: &mut ::bevy_butler::__internal::CodegenEntries) {\n";
+        for entry in &self.entries {
+            for cfg in &entry.cfgs {
+                source += &format!("    {cfg}\n");
+            }
+            source += &format!("    entries.add(&crate::{});\n", entry.path);
+        }
+        source += "}\n";
+        source
+    }
+}
+
+/// The name of the static that a butler macro generates for an entry.
+///
+/// This needs to be kept in sync with `entry_static_ident` in `bevy-butler-proc-macro`.
+fn static_name(macro_name: &str, item: &str, args: &TokenStream) -> String {
+    let args: String = args.to_string().split_whitespace().collect();
+    let hash = sha256::digest(format!("{macro_name}:{item}:{args}"));
+    format!("_butler_{macro_name}_{hash}")
+}
+
+/// Fails if `module` has entries, but can't be named from the crate root.
+fn check_visibility(vis: &Visibility, module: &Module, has_entries: bool) -> Result<(), Error> {
+    let visible = match vis {
+        _ if module.path.len() == 1 => true,
+        Visibility::Public(_) => true,
+        Visibility::Restricted(restricted) => {
+            restricted.path.is_ident("crate")
+                || (restricted.path.is_ident("super") && module.path.len() == 2)
+        }
+        Visibility::Inherited => false,
+    };
+
+    match visible || !has_entries {
+        true => Ok(()),
+        false => Err(Error::PrivateModule(module.path.join("::"))),
+    }
+}
+
+fn is_macro(path: &syn::Path, name: &str) -> bool {
+    path.segments.last().is_some_and(|s| s.ident == name)
+}
+
+fn cfg_attrs(attrs: &[Attribute]) -> impl Iterator<Item = String> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .map(|attr| {
+            let tokens = match &attr.meta {
+                Meta::List(list) => list.tokens.to_string(),
+                _ => String::new(),
+            };
+            format!("#[cfg({tokens})]")
+        })
+}
+
+/// The attributes of an item, including the ones inside `#[cfg_attr(...)]`, along with the
+/// `#[cfg]` attributes that they depend on.
+fn entry_attrs(attrs: &[Attribute]) -> Vec<(Vec<String>, Meta)> {
+    fn expand(cfgs: Vec<String>, meta: Meta, out: &mut Vec<(Vec<String>, Meta)>) {
+        let nested = match &meta {
+            Meta::List(list) if list.path.is_ident("cfg_attr") => list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok(),
+            _ => None,
+        };
+        let Some(nested) = nested else {
+            out.push((cfgs, meta));
+            return;
+        };
+
+        let mut nested = nested.into_iter();
+        let Some(predicate) = nested.next() else {
+            return;
+        };
+        let mut cfgs = cfgs;
+        cfgs.push(format!("#[cfg({})]", predicate.to_token_stream()));
+        for meta in nested {
+            expand(cfgs.clone(), meta, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    for attr in attrs {
+        expand(Vec::new(), attr.meta.clone(), &mut out);
+    }
+    out
+}
+
+fn path_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("path"))
+        .and_then(|attr| match &attr.meta {
+            Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(path),
+                    ..
+                }) => Some(path.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Fn(i) => &i.attrs,
+        Item::Struct(i) => &i.attrs,
+        Item::Enum(i) => &i.attrs,
+        Item::Use(i) => &i.attrs,
+        Item::Type(i) => &i.attrs,
+        Item::Mod(i) => &i.attrs,
+        Item::Macro(i) => &i.attrs,
+        _ => &[],
+    }
+}
+
+/// The name that butler macros identify an item by, matching `get_fn_ident`,
+/// `get_struct_or_enum_ident` and `get_use_path` in `bevy-butler-proc-macro`.
+fn item_name(item: &Item) -> Option<String> {
+    fn use_name(tree: &UseTree) -> Option<String> {
+        match tree {
+            UseTree::Path(path) => use_name(&path.tree),
+            UseTree::Name(name) => Some(name.ident.to_string()),
+            UseTree::Rename(rename) => Some(rename.rename.to_string()),
+            UseTree::Group(_) | UseTree::Glob(_) => None,
+        }
+    }
+
+    match item {
+        Item::Fn(i) => Some(i.sig.ident.to_string()),
+        Item::Struct(i) => Some(i.ident.to_string()),
+        Item::Enum(i) => Some(i.ident.to_string()),
+        Item::Type(i) => Some(i.ident.to_string()),
+        Item::Use(i) => use_name(&i.tree),
+        _ => None,
+    }
+}
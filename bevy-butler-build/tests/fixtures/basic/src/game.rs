@@ -0,0 +1,3 @@
+pub(crate) mod physics;
+
+pub fn greet() {}
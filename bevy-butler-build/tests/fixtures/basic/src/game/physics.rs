@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = crate::MyPlugin)]
+struct Gravity(f32);
+
+pub(crate) mod inline {
+    #[bevy_butler::add_event(plugin = crate::MyPlugin)]
+    #[derive(bevy::prelude::Event)]
+    struct Collision;
+}
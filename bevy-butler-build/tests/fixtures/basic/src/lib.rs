@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+bevy_butler::include_butler_entries!();
+
+mod game;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn hello() {}
+
+#[add_system(plugin = MyPlugin, schedule = Startup)]
+#[add_system(plugin = MyPlugin, schedule = Update)]
+use game::greet as greet_twice;
+
+butler_chain!(plugin = MyPlugin, schedule = Update, [hello, greet_twice]);
+
+#[cfg(feature = "debug")]
+mod debug {
+    #[bevy_butler::register_type(plugin = crate::MyPlugin)]
+    #[derive(bevy::prelude::Reflect)]
+    struct DebugInfo;
+}
+
+fn no_entries() {}
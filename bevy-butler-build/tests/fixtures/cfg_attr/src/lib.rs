@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+bevy_butler::include_butler_entries!();
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[cfg_attr(feature = "debug", add_system(plugin = MyPlugin, schedule = Update))]
+fn debug_only() {}
+
+#[cfg_attr(
+    all(feature = "debug", not(test)),
+    derive(Resource),
+    insert_resource(plugin = MyPlugin, init = Flag)
+)]
+struct Flag;
+
+#[cfg_attr(feature = "debug", cfg_attr(test, add_system(plugin = MyPlugin, schedule = Startup)))]
+fn nested() {}
+
+#[cfg_attr(feature = "debug", allow(dead_code))]
+fn not_an_entry() {}
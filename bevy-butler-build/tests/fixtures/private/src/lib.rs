@@ -0,0 +1,6 @@
+mod outer {
+    mod inner {
+        #[bevy_butler::add_system(plugin = crate::MyPlugin, schedule = Update)]
+        fn hidden() {}
+    }
+}
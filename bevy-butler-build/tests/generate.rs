@@ -0,0 +1,115 @@
+use bevy_butler_build::{Builder, Error};
+
+fn generate(fixture: &str) -> Result<String, Error> {
+    Builder::new()
+        .root(format!("tests/fixtures/{fixture}/src/lib.rs"))
+        .to_source()
+}
+
+fn entry_lines(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("entries.add") || line.starts_with("#[cfg"))
+        .collect()
+}
+
+#[test]
+fn static_name() {
+    let source = generate("basic").unwrap();
+
+    // Checked against the macro output in `bevy-butler/tests/add_system/static_name.rs`
+    assert!(source.contains(
+        "entries.add(&crate::_butler_add_system_e8f43da6278dd5d1ddf8dae407fe9c583e624d4ba8a88e447bf97e8816e5227a);"
+    ));
+}
+
+#[test]
+fn modules() {
+    let source = generate("basic").unwrap();
+    let lines = entry_lines(&source);
+
+    let paths: Vec<_> = lines
+        .iter()
+        .filter_map(|line| line.strip_prefix("entries.add(&"))
+        .map(|path| &path[..path.rfind("::").unwrap()])
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "crate::game::physics",
+            "crate::game::physics::inline",
+            "crate",
+            "crate",
+            "crate",
+            "crate",
+            "crate::debug",
+        ]
+    );
+
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("entries.add(&crate::_butler_butler_chain_")));
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("entries.add(&crate::debug::_butler_register_type_")));
+}
+
+#[test]
+fn cfg() {
+    let source = generate("basic").unwrap();
+    let lines = entry_lines(&source);
+
+    let cfg = lines
+        .iter()
+        .position(|line| line.starts_with("#[cfg"))
+        .unwrap();
+    assert_eq!(lines[cfg], "#[cfg(feature = \"debug\")]");
+    assert!(lines[cfg + 1].starts_with("entries.add(&crate::debug::"));
+    assert_eq!(
+        lines
+            .iter()
+            .filter(|line| line.starts_with("#[cfg"))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn private_module() {
+    match generate("private") {
+        Err(Error::PrivateModule(path)) => assert_eq!(path, "outer::inner"),
+        other => panic!("Expected a private module error, got {other:?}"),
+    }
+}
+
+#[test]
+fn cfg_attr() {
+    let source = generate("cfg_attr").unwrap();
+    let lines = entry_lines(&source);
+
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[0], "#[cfg(feature = \"debug\")]");
+    assert!(lines[1].starts_with("entries.add(&crate::_butler_add_system_"));
+    assert_eq!(
+        lines[2].replace(' ', ""),
+        "#[cfg(all(feature=\"debug\",not(test)))]"
+    );
+    assert!(lines[3].starts_with("entries.add(&crate::_butler_insert_resource_"));
+    assert_eq!(lines[4], "#[cfg(feature = \"debug\")]");
+    assert_eq!(lines[5], "#[cfg(test)]");
+    assert!(lines[6].starts_with("entries.add(&crate::_butler_add_system_"));
+}
+
+#[test]
+fn entry_names() {
+    let source = generate("basic").unwrap();
+
+    // Listed relative to the crate root, to be compared with `module_path!()`
+    assert!(source.contains(
+        "\"_butler_add_system_e8f43da6278dd5d1ddf8dae407fe9c583e624d4ba8a88e447bf97e8816e5227a\","
+    ));
+    assert!(source
+        .lines()
+        .any(|line| line.trim().starts_with("\"game::physics::inline::_butler_")));
+}
@@ -1,31 +1,83 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::quote;
 use structs::*;
 use syn::Item;
 
-use crate::utils::{butler_plugin_entry_block, get_struct_or_enum_ident};
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident,
+    get_struct_or_enum_ident,
+};
 
 pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let attr: EventAttr = deluxe::parse(attr)?;
     let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("add_event", &args, &item)?;
     let event_ident = get_struct_or_enum_ident(&item)?;
 
     let plugin = &attr.plugin;
     let generics = &attr.generics;
 
-    let mut hash_bytes = event_ident.to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &generics.to_token_stream().to_string();
-    let static_ident = format_ident!("_butler_event_{}", sha256::digest(hash_bytes));
+    let static_ident = entry_static_ident("add_event", event_ident, &args);
+
+    let event_ty = quote!(#event_ident #generics);
+    let events_ty = quote!(::bevy_butler::__internal::bevy_ecs::event::Events<#event_ty>);
+    let last = quote!(::bevy_butler::__internal::bevy_app::Last);
+
+    // There is no `ReflectEvent` type data, so we register the `Events<T>`
+    // resource instead, which carries `ReflectResource` when reflected.
+    let reflect_stmt = attr.reflect.is_set().then(|| {
+        quote! {
+            app.register_type::<#event_ty>()
+                .register_type::<#events_ty>();
+        }
+    });
+
+    let add_stmt = if attr.trigger_only.is_set() {
+        // Trigger-only events don't get an `Events<T>` resource in any build, so that an
+        // `EventReader` or `EventWriter` fails the same way in debug and release builds
+        quote! {}
+    } else if attr.manual_update.is_set() {
+        // Skip registering to the `EventRegistry`, so the events are never updated automatically
+        quote! { app.init_resource::<#events_ty>(); }
+    } else {
+        quote! { app.add_event::<#event_ty>(); }
+    };
+
+    let log_stmt = attr.debug_log.is_set().then(|| {
+        quote! {
+            app.add_systems(#last, ::bevy_butler::__internal::log_events::<#event_ty>);
+        }
+    });
+
+    let record_stmt = attr.record.is_set().then(|| {
+        let capacity = attr
+            .record_capacity
+            .as_ref()
+            .map(|c| quote!(#c))
+            .unwrap_or_else(|| quote!(600));
+        quote! {
+            ::bevy_butler::_butler_record_events!(app, #event_ty, #capacity);
+        }
+    });
 
     let register_block = butler_plugin_entry_block(
         &static_ident,
         &attr.plugin,
+        &entry_name(&event_ty),
         &syn::parse_quote! {
-            |app| { app.add_event::<#event_ident #generics>(); }
+            |app| {
+                if cfg!(debug_assertions) {
+                    ::bevy_butler::__internal::check_duplicate_registration::<#plugin, #event_ty>(app, "Event");
+                }
+                #add_stmt
+                #reflect_stmt
+                #log_stmt
+                #record_stmt
+            }
         },
     );
 
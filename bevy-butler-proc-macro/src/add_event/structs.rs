@@ -1,8 +1,41 @@
-use deluxe::ParseMetaItem;
-use syn::{AngleBracketedGenericArguments, Path};
+use deluxe::{Flag, ParseMetaItem};
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+fn validate(input: EventAttr) -> deluxe::Result<EventAttr> {
+    if input.trigger_only.is_set() {
+        for (name, flag) in [
+            ("manual_update", &input.manual_update),
+            ("debug_log", &input.debug_log),
+            ("record", &input.record),
+        ] {
+            if flag.is_set() {
+                return Err(deluxe::Error::new_spanned(
+                    flag,
+                    format!("`{name}` cannot be used with `trigger_only`"),
+                ));
+            }
+        }
+    }
+
+    if let (false, Some(capacity)) = (input.record.is_set(), &input.record_capacity) {
+        return Err(deluxe::Error::new_spanned(
+            capacity,
+            "`record_capacity` requires `record`",
+        ));
+    }
+
+    Ok(input)
+}
 
 #[derive(ParseMetaItem)]
+#[deluxe(and_then = validate)]
 pub(crate) struct EventAttr {
     pub plugin: Path,
     pub generics: Option<AngleBracketedGenericArguments>,
+    pub reflect: Flag,
+    pub manual_update: Flag,
+    pub debug_log: Flag,
+    pub trigger_only: Flag,
+    pub record: Flag,
+    pub record_capacity: Option<Expr>,
 }
@@ -1,10 +1,12 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::{quote, ToTokens};
 use structs::ObserverAttr;
 use syn::{Expr, Ident, Item};
 
-use crate::utils::{butler_plugin_entry_block, get_fn_ident};
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident, get_fn_ident,
+};
 
 pub(crate) mod structs;
 
@@ -14,26 +16,47 @@ pub(crate) fn parse_observer(attr: &ObserverAttr, ident: &Ident) -> syn::Result<
 }
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let attr: ObserverAttr = deluxe::parse(attr)?;
     let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("add_observer", &args, &item)?;
     let ident = get_fn_ident(&item)?;
 
     let plugin = &attr.plugin;
     let obsrv_expr = parse_observer(&attr, ident)?;
 
-    let mut hash_bytes = "observer".to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &obsrv_expr.to_token_stream().to_string();
+    let static_ident = entry_static_ident("add_observer", ident, &args);
 
-    let static_ident = format_ident!("_butler_observer_{}", sha256::digest(hash_bytes));
+    let name = match &attr.name {
+        Some(name) => quote!(#name),
+        None => {
+            let fn_name = obsrv_expr.to_token_stream().to_string().replace(' ', "");
+            quote!(::core::concat!(::core::module_path!(), "::", #fn_name))
+        }
+    };
+    let name = quote!(::bevy_butler::__internal::bevy_ecs::name::Name::new(#name));
 
-    let register_block = butler_plugin_entry_block(
-        &static_ident,
-        plugin,
-        &syn::parse_quote! {
-            |app| { app.add_observer( #obsrv_expr ); }
+    let entry_expr = match (&attr.on_component, &attr.on_spawn_of) {
+        (Some(component), _) => syn::parse_quote! {
+            |app| {
+                ::bevy_butler::__internal::add_entity_observer::<#plugin, #component, _, _, _, _>(app, #obsrv_expr, #name, true);
+            }
         },
-    );
+        (None, Some(component)) => syn::parse_quote! {
+            |app| {
+                ::bevy_butler::__internal::add_entity_observer::<#plugin, #component, _, _, _, _>(app, #obsrv_expr, #name, false);
+            }
+        },
+        (None, None) => syn::parse_quote! {
+            |app| {
+                app.world_mut()
+                    .add_observer( #obsrv_expr )
+                    .insert((#name, ::bevy_butler::ButlerObserver::<#plugin>::default()));
+            }
+        },
+    };
+
+    let register_block = butler_plugin_entry_block(&static_ident, plugin, &entry_name(&obsrv_expr), &entry_expr);
 
     Ok(quote! {
         #item
@@ -1,8 +1,23 @@
 use deluxe::ParseMetaItem;
-use syn::{AngleBracketedGenericArguments, Path};
+use syn::{AngleBracketedGenericArguments, Expr, Path, Type};
+
+fn validate(input: ObserverAttr) -> deluxe::Result<ObserverAttr> {
+    if let (Some(_), Some(spawn_of)) = (&input.on_component, &input.on_spawn_of) {
+        return Err(deluxe::Error::new_spanned(
+            spawn_of,
+            "`on_component` and `on_spawn_of` are mutually exclusive",
+        ));
+    }
+
+    Ok(input)
+}
 
 #[derive(ParseMetaItem)]
+#[deluxe(and_then = validate)]
 pub(crate) struct ObserverAttr {
     pub plugin: Path,
     pub generics: Option<AngleBracketedGenericArguments>,
+    pub on_component: Option<Type>,
+    pub on_spawn_of: Option<Type>,
+    pub name: Option<Expr>,
 }
@@ -1,16 +1,21 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::quote;
 use structs::{AddPluginAttr, ButlerTarget};
 use syn::{parse, parse_quote, Fields, Item, ItemStruct};
 
-use crate::utils::{butler_plugin_entry_block, butler_plugin_group_entry_block, get_struct_or_enum_ident};
+use crate::utils::{
+    butler_plugin_entry_block, butler_plugin_group_entry_block, check_duplicate_attr, entry_name,
+    entry_static_ident, get_struct_or_enum_ident,
+};
 
 pub mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let mut attr: AddPluginAttr = deluxe::parse(attr)?;
     let item: Item = parse(body)?;
+    check_duplicate_attr("add_plugin", &args, &item)?;
     let plugin_ident = get_struct_or_enum_ident(&item)?;
 
     if let Item::Struct(ItemStruct { ident, fields, .. }) = &item {
@@ -28,22 +33,15 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
 
     let register = attr.register_statement(plugin_ident)?;
 
-    let hash = sha256::digest(
-        [
-            plugin_ident.to_token_stream().to_string(),
-            attr.target.to_string(),
-            generics.to_token_stream().to_string(),
-        ]
-        .concat(),
-    );
-    let static_ident = format_ident!("_butler_add_plugin_{}", hash);
+    let static_ident = entry_static_ident("add_plugin", plugin_ident, &args);
 
+    let name = entry_name(quote!(#plugin_ident #generics));
     let register_block = match attr.target {
         ButlerTarget::Plugin(target) => {
-            butler_plugin_entry_block(&static_ident, &target, &register)
+            butler_plugin_entry_block(&static_ident, &target, &name, &register)
         }
         ButlerTarget::PluginGroup(group) => {
-            butler_plugin_group_entry_block(&static_ident, &group, &register)
+            butler_plugin_group_entry_block(&static_ident, &group, &name, &register)
         }
     };
 
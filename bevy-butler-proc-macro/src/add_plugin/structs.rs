@@ -9,11 +9,11 @@ pub(crate) enum ButlerTarget {
     PluginGroup(Path),
 }
 
-impl ToString for ButlerTarget {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for ButlerTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Plugin(p) => format!("Plugin({p:?})"),
-            Self::PluginGroup(g) => format!("PluginGroup({g:?})"),
+            Self::Plugin(p) => write!(f, "Plugin({p:?})"),
+            Self::PluginGroup(g) => write!(f, "PluginGroup({g:?})"),
         }
     }
 }
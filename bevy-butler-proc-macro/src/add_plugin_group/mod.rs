@@ -1,6 +1,6 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::quote;
 use structs::AddPluginGroupAttr;
 use syn::{
     parse, parse_quote, ExprClosure, Fields, Item, ItemStruct,
@@ -8,26 +8,26 @@ use syn::{
 
 use crate::{
     add_plugin::structs::ButlerTarget,
-    utils::{butler_plugin_entry_block, butler_plugin_group_entry_block, get_struct_or_enum_ident},
+    utils::{butler_plugin_entry_block, butler_plugin_group_entry_block, check_duplicate_attr, entry_name, entry_static_ident, get_struct_or_enum_ident},
 };
 
 pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let mut attr: AddPluginGroupAttr = deluxe::parse(attr)?;
     let item: Item = parse(body)?;
+    check_duplicate_attr("add_plugin_group", &args, &item)?;
 
     let plugin_ident = get_struct_or_enum_ident(&item)?;
 
     if let Item::Struct(ItemStruct { fields, ident, .. }) = &item {
-        if attr.init.is_none() {
-            if fields.is_empty() {
-                // Unit structs can be initialized using themselves
-                match fields {
-                    Fields::Unit => attr.init = Some(parse_quote!(#ident)),
-                    Fields::Named(_) => attr.init = Some(parse_quote!(#ident {})),
-                    Fields::Unnamed(_) => attr.init = Some(parse_quote!(#ident ())),
-                }
+        if attr.init.is_none() && fields.is_empty() {
+            // Unit structs can be initialized using themselves
+            match fields {
+                Fields::Unit => attr.init = Some(parse_quote!(#ident)),
+                Fields::Named(_) => attr.init = Some(parse_quote!(#ident {})),
+                Fields::Unnamed(_) => attr.init = Some(parse_quote!(#ident ())),
             }
         }
     }
@@ -43,18 +43,9 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
     });
     let init = &attr.init;
 
-    let static_ident = format_ident!(
-        "_butler_add_plugin_group_{}",
-        sha256::digest(
-            [
-                plugin_ident.to_token_stream().to_string(),
-                attr.target.to_string(),
-                generics.to_token_stream().to_string(),
-            ]
-            .concat()
-        )
-    );
+    let static_ident = entry_static_ident("add_plugin_group", plugin_ident, &args);
 
+    let name = entry_name(quote!(#plugin_ident #generics));
     let register_block = match attr.target {
         ButlerTarget::Plugin(target) => {
             let register: ExprClosure = parse_quote! { |app| {
@@ -62,7 +53,7 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
                 app.add_plugins(plugin);
             }};
 
-            butler_plugin_entry_block(&static_ident, &target, &register)
+            butler_plugin_entry_block(&static_ident, &target, &name, &register)
         }
         ButlerTarget::PluginGroup(target) => {
             let register = parse_quote! { |builder| {
@@ -70,7 +61,7 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
                 builder.add_group(group)
             }};
 
-            butler_plugin_group_entry_block(&static_ident, &target, &register)
+            butler_plugin_group_entry_block(&static_ident, &target, &name, &register)
         }
     };
 
@@ -1,26 +1,34 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::{quote, ToTokens};
 use structs::SystemAttr;
 use syn::Expr;
 use syn::{Ident, Item};
 
-use crate::utils::{butler_plugin_entry_block, get_fn_ident};
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident, get_fn_ident,
+};
 
 pub mod structs;
 
-pub(crate) fn parse_system(attr: &SystemAttr, ident: &Ident) -> Expr {
+/// The annotated system function, with its generic arguments.
+pub(crate) fn system_fn(attr: &SystemAttr, ident: &Ident) -> Expr {
     let generics = attr.generics.clone().map(|mut g| {
         g.colon2_token = Some(Default::default());
         g
     });
+    syn::parse_quote!(#ident #generics)
+}
+
+/// Applies the transforms, pipes and run conditions of `attr` to `system`.
+pub(crate) fn parse_system(attr: &SystemAttr, system: &Expr) -> Expr {
     let transforms = &attr.transforms.0;
 
     let sys_expr: Expr = syn::parse_quote! {
-        #ident #generics #(. #transforms)*
+        #system #(. #transforms)*
     };
 
-    match &attr.pipe_in {
+    let sys_expr = match &attr.pipe_in {
         Some(pipes) if !pipes.is_empty() => {
             let mut iter = pipes.iter();
             let first = iter.next().unwrap();
@@ -29,32 +37,60 @@ pub(crate) fn parse_system(attr: &SystemAttr, ident: &Ident) -> Expr {
             }
         }
         _ => sys_expr,
+    };
+
+    match attr.interval_condition() {
+        Some(condition) => syn::parse_quote! {
+            ::bevy_butler::__internal::bevy_ecs::schedule::IntoScheduleConfigs::run_if(#sys_expr, #condition)
+        },
+        None => sys_expr,
     }
 }
 
 pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let attr: SystemAttr = deluxe::parse(attr)?;
     let input: Item = syn::parse(item)?;
+    check_duplicate_attr("add_system", &args, &input)?;
 
     let sys_ident = get_fn_ident(&input)?;
 
     let plugin = &attr.plugin;
-    let schedule = &attr.schedule;
+    let schedule = &attr.schedule();
+
+    let system = system_fn(&attr, sys_ident);
+    let sys_expr = parse_system(&attr, &system);
 
-    let sys_expr = parse_system(&attr, sys_ident);
+    let static_ident = entry_static_ident("add_system", sys_ident, &args);
 
-    let mut hash_bytes = "system".to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &schedule.to_token_stream().to_string();
-    hash_bytes += &sys_expr.to_token_stream().to_string();
-    #[allow(unused_variables)] // It's actually used
-    let static_ident = format_ident!("_butler_system_{}", sha256::digest(hash_bytes));
+    let sys_name = system.to_token_stream().to_string().replace(' ', "");
+    let instrumented_expr = parse_system(&attr, &syn::parse_quote!(system));
+    let add_instrumented = quote! {
+        let system = ::bevy_butler::__internal::instrument_system::<#plugin, _, _, _, _>(
+            app,
+            ::core::concat!(::core::module_path!(), "::", #sys_name),
+            #system,
+        );
+        app.add_systems( #schedule, #instrumented_expr );
+    };
+
+    let add_stmt = match attr.instrument.is_set() {
+        true => add_instrumented,
+        false => quote! {
+            if <#plugin as ::bevy_butler::__internal::ButlerPluginMarker>::INSTRUMENT {
+                #add_instrumented
+            } else {
+                app.add_systems( #schedule, #sys_expr );
+            }
+        },
+    };
 
     let register_block = butler_plugin_entry_block(
         &static_ident,
         plugin,
+        &entry_name(&system),
         &syn::parse_quote! {
-            |app| { app.add_systems( #schedule, #sys_expr ); }
+            |app| { #add_stmt }
         },
     );
 
@@ -1,7 +1,8 @@
 use std::borrow::Borrow;
 
-use deluxe::{ParseMetaItem, ParseMetaRest};
-use deluxe_core::parse_helpers::skip_meta_item;
+use deluxe::{Flag, ParseMetaItem, ParseMetaRest};
+use deluxe_core::parse_helpers::{closest_field, skip_meta_item, unknown_error};
+use proc_macro2::Span;
 use quote::quote;
 use syn::parse::discouraged::AnyDelimiter;
 use syn::parse::{Parse, ParseBuffer};
@@ -21,6 +22,37 @@ fn parse_end_comma_or_eof(input: &ParseBuffer<'_>) -> deluxe::Result<()> {
     Ok(())
 }
 
+/// Methods of systems and `IntoScheduleConfigs`, which are never mistaken for misspelled fields.
+const KNOWN_TRANSFORMS: &[&str] = &[
+    "in_set",
+    "before",
+    "before_ignore_deferred",
+    "after",
+    "after_ignore_deferred",
+    "distributive_run_if",
+    "run_if",
+    "ambiguous_with",
+    "ambiguous_with_all",
+    "chain",
+    "chain_ignore_deferred",
+    "pipe",
+    "map",
+];
+
+/// Rejects a transform that looks like a typo of one of `fields`, like `schdule`.
+///
+/// Without this, a misspelled field would be passed through as a method call on the system.
+fn check_misspelled_field(name: &str, span: Span, fields: &[&str]) -> deluxe::Result<()> {
+    if KNOWN_TRANSFORMS.contains(&name) {
+        return Ok(());
+    }
+
+    match closest_field(name, fields) {
+        Some(_) => Err(unknown_error(name, span, fields)),
+        None => Ok(()),
+    }
+}
+
 impl ParseMetaRest for TransformList {
     fn parse_meta_rest<'s, S: Borrow<ParseBuffer<'s>>>(
         inputs: &[S],
@@ -32,13 +64,14 @@ impl ParseMetaRest for TransformList {
             let input = input.borrow();
             while !input.is_empty() {
                 let path = Path::parse(input)?;
-                if path
-                    .get_ident()
-                    .is_some_and(|i| exclude.contains(&i.to_string().as_str()))
-                {
-                    skip_meta_item(input);
-                    parse_end_comma_or_eof(input)?;
-                    continue;
+                if let Some(ident) = path.get_ident() {
+                    let name = ident.to_string();
+                    if exclude.contains(&name.as_str()) {
+                        skip_meta_item(input);
+                        parse_end_comma_or_eof(input)?;
+                        continue;
+                    }
+                    check_misspelled_field(&name, ident.span(), exclude)?;
                 }
 
                 // Style 1: Path - transform
@@ -70,12 +103,63 @@ impl ParseMetaRest for TransformList {
     }
 }
 
+fn validate(input: SystemAttr) -> deluxe::Result<SystemAttr> {
+    match (&input.schedule, input.fixed.is_set()) {
+        (Some(schedule), true) => {
+            return Err(deluxe::Error::new_spanned(
+                schedule,
+                "`schedule` cannot be used with `fixed`, which always targets `FixedUpdate`",
+            ))
+        }
+        (None, false) => {
+            return Err(deluxe::Error::new(
+                Span::call_site(),
+                "missing required field `schedule`",
+            ))
+        }
+        _ => {}
+    }
+
+    if let (Some(_), Some(every_frames)) = (&input.every, &input.every_frames) {
+        return Err(deluxe::Error::new_spanned(
+            every_frames,
+            "`every` and `every_frames` are mutually exclusive",
+        ));
+    }
+
+    Ok(input)
+}
+
 #[derive(Clone, ParseMetaItem)]
+#[deluxe(and_then = validate)]
 pub(crate) struct SystemAttr {
     pub plugin: Path,
-    pub schedule: Expr,
+    pub schedule: Option<Expr>,
+    pub fixed: Flag,
+    pub every: Option<Expr>,
+    pub every_frames: Option<Expr>,
+    pub instrument: Flag,
     pub generics: Option<AngleBracketedGenericArguments>,
     pub pipe_in: Option<Vec<Expr>>,
     #[deluxe(rest)]
     pub transforms: TransformList,
 }
+
+impl SystemAttr {
+    /// The schedule to add the system to, which is `FixedUpdate` if `fixed` is set.
+    pub fn schedule(&self) -> Expr {
+        match &self.schedule {
+            Some(schedule) => schedule.clone(),
+            None => syn::parse_quote!(::bevy_butler::__internal::bevy_app::FixedUpdate),
+        }
+    }
+
+    /// The run condition generated from `every` or `every_frames`, if either is set.
+    pub fn interval_condition(&self) -> Option<Expr> {
+        match (&self.every, &self.every_frames) {
+            (Some(duration), _) => Some(syn::parse_quote!(::bevy_butler::__internal::every(#duration))),
+            (None, Some(frames)) => Some(syn::parse_quote!(::bevy_butler::__internal::every_frames(#frames))),
+            (None, None) => None,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use structs::ChainAttr;
+use syn::{parse::Parser, punctuated::Punctuated, Error, Expr, Token};
+
+use crate::utils::{butler_plugin_entry_block, entry_name, entry_static_ident};
+
+pub(crate) mod structs;
+
+fn is_punct(token: Option<&TokenTree>, ch: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(p)) if p.as_char() == ch)
+}
+
+/// Splits the bracketed list of systems out of the macro input, leaving the rest as arguments.
+fn split_systems(input: TokenStream2) -> syn::Result<(TokenStream2, Punctuated<Expr, Token![,]>)> {
+    let mut args = Vec::new();
+    let mut systems = None;
+    let mut skip_comma = false;
+
+    for token in input {
+        match token {
+            // A bracket directly after `=` is an argument value, not the system list
+            TokenTree::Group(group)
+                if group.delimiter() == Delimiter::Bracket && !is_punct(args.last(), '=') =>
+            {
+                if systems.is_some() {
+                    return Err(Error::new_spanned(
+                        group,
+                        "Expected a single list of systems",
+                    ));
+                }
+                systems = Some(Punctuated::parse_terminated.parse2(group.stream())?);
+
+                // Drop the separator between the list and the surrounding arguments
+                match is_punct(args.last(), ',') {
+                    true => drop(args.pop()),
+                    false => skip_comma = true,
+                }
+            }
+            TokenTree::Punct(p) if skip_comma && p.as_char() == ',' => skip_comma = false,
+            token => {
+                skip_comma = false;
+                args.push(token);
+            }
+        }
+    }
+
+    let Some(systems) = systems else {
+        return Err(Error::new(
+            Span::call_site(),
+            "Expected a list of systems, like `[first_system, second_system]`",
+        ));
+    };
+
+    Ok((args.into_iter().collect(), systems))
+}
+
+pub(crate) fn macro_impl(input: TokenStream1) -> syn::Result<TokenStream2> {
+    let input = TokenStream2::from(input);
+    let (args, systems) = split_systems(input.clone())?;
+    let attr: ChainAttr = deluxe::parse2(args)?;
+
+    let plugin = &attr.plugin;
+    let schedule = &attr.schedule;
+    let transforms = &attr.transforms.0;
+    let name = entry_name(quote!([#systems]));
+    let systems = systems.iter();
+
+    let sys_expr: Expr = syn::parse_quote! {
+        ::bevy_butler::__internal::bevy_ecs::schedule::IntoScheduleConfigs::chain(( #(#systems,)* ))
+            #(. #transforms)*
+    };
+
+    let static_ident = entry_static_ident("butler_chain", "", &input);
+
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        plugin,
+        &name,
+        &syn::parse_quote! {
+            |app| { app.add_systems( #schedule, #sys_expr ); }
+        },
+    );
+
+    // Function-like macros can't see each other, so every invocation leaves a macro named after
+    // its entry behind. A later identical invocation passes its error to it, instead of to the
+    // fallback that discards it
+    Ok(quote! {
+        const _: () = {
+            #[allow(unused_imports)]
+            use ::bevy_butler::_butler_chain_unique as #static_ident;
+            #static_ident! {
+                ::core::compile_error!(
+                    "duplicate `butler_chain!` invocation, which would register the same entry twice"
+                );
+            }
+        };
+
+        #[doc(hidden)]
+        macro_rules! #static_ident {
+            ($($error:tt)*) => { $($error)* };
+        }
+
+        #register_block
+    })
+}
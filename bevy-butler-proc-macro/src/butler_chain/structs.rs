@@ -0,0 +1,12 @@
+use deluxe::ParseMetaItem;
+use syn::{Expr, Path};
+
+use crate::add_system::structs::TransformList;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ChainAttr {
+    pub plugin: Path,
+    pub schedule: Expr,
+    #[deluxe(rest)]
+    pub transforms: TransformList,
+}
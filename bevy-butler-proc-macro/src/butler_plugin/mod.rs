@@ -5,8 +5,12 @@ use syn::{
     parse_quote, Error, FnArg, Ident, ImplItem, Item, ItemEnum, ItemImpl, ItemStruct, Pat, TypePath,
 };
 
+use crate::utils::codegen_enabled;
+
 #[derive(deluxe::ParseMetaItem)]
-pub struct ButlerPluginAttr;
+pub struct ButlerPluginAttr {
+    pub instrument: deluxe::Flag,
+}
 
 pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<TokenStream2> {
     let attr: ButlerPluginAttr = deluxe::parse(attr)?;
@@ -22,22 +26,46 @@ pub(crate) fn macro_impl(attr: TokenStream1, item: TokenStream1) -> syn::Result<
     }
 }
 
-fn register_butler_plugin_stmts(plugin: &TypePath) -> TokenStream2 {
-    quote! {
-        impl #plugin {
-            pub(crate) fn _butler_plugin_sealed_marker() -> ::std::any::TypeId {
-                struct SealedMarker;
+fn register_butler_plugin_stmts(attr: &ButlerPluginAttr, plugin: &TypePath) -> TokenStream2 {
+    let instrument = attr
+        .instrument
+        .is_set()
+        .then(|| quote!(const INSTRUMENT: bool = true;));
 
-                ::std::any::TypeId::of::<SealedMarker>()
+    let submit_codegen_entries = codegen_enabled().then(|| {
+        quote! {
+            fn submit_codegen_entries() {
+                ::bevy_butler::_butler_submit_codegen_entries!();
             }
         }
+    });
+
+    quote! {
+        impl ::bevy_butler::__internal::ButlerPluginMarker for #plugin {
+            #instrument
+
+            // Plugins with the same path can be declared in separate function bodies
+            const MARKER: u64 = ::bevy_butler::__internal::butler_plugin_marker(::core::concat!(
+                ::core::file!(),
+                ":",
+                ::core::line!(),
+                ":",
+                ::core::column!(),
+                ":",
+                ::core::module_path!(),
+                "::",
+                ::core::stringify!(#plugin),
+            ));
+        }
 
-        impl ::bevy_butler::__internal::ButlerPlugin for #plugin {}
+        impl ::bevy_butler::__internal::ButlerPlugin for #plugin {
+            #submit_codegen_entries
+        }
     }
 }
 
-pub(crate) fn struct_impl(_attr: ButlerPluginAttr, item: ItemStruct) -> syn::Result<TokenStream2> {
-    let impl_block = impl_plugin_block(_attr, &item.ident)?;
+pub(crate) fn struct_impl(attr: ButlerPluginAttr, item: ItemStruct) -> syn::Result<TokenStream2> {
+    let impl_block = impl_plugin_block(attr, &item.ident)?;
 
     Ok(quote! {
         #item
@@ -46,8 +74,8 @@ pub(crate) fn struct_impl(_attr: ButlerPluginAttr, item: ItemStruct) -> syn::Res
     })
 }
 
-pub(crate) fn enum_impl(_attr: ButlerPluginAttr, item: ItemEnum) -> syn::Result<TokenStream2> {
-    let impl_block = impl_plugin_block(_attr, &item.ident)?;
+pub(crate) fn enum_impl(attr: ButlerPluginAttr, item: ItemEnum) -> syn::Result<TokenStream2> {
+    let impl_block = impl_plugin_block(attr, &item.ident)?;
 
     Ok(quote! {
         #item
@@ -57,15 +85,15 @@ pub(crate) fn enum_impl(_attr: ButlerPluginAttr, item: ItemEnum) -> syn::Result<
 }
 
 pub(crate) fn impl_plugin_block(
-    _attr: ButlerPluginAttr,
+    attr: ButlerPluginAttr,
     ident: &Ident,
 ) -> syn::Result<TokenStream2> {
-    let register_block = register_butler_plugin_stmts(&syn::parse2(quote!(#ident))?);
+    let register_block = register_butler_plugin_stmts(&attr, &syn::parse2(quote!(#ident))?);
 
     Ok(quote! {
         impl ::bevy_butler::__internal::bevy_app::Plugin for #ident {
             fn build(&self, app: &mut ::bevy_butler::__internal::bevy_app::App) {
-                <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(app, Self::_butler_plugin_sealed_marker());
+                <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(app);
             }
         }
 
@@ -73,10 +101,10 @@ pub(crate) fn impl_plugin_block(
     })
 }
 
-pub(crate) fn impl_impl(_attr: ButlerPluginAttr, mut body: ItemImpl) -> syn::Result<TokenStream2> {
+pub(crate) fn impl_impl(attr: ButlerPluginAttr, mut body: ItemImpl) -> syn::Result<TokenStream2> {
     let register_block = |app_ident: &Ident| {
         syn::parse2(quote!(
-            <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(#app_ident, Self::_butler_plugin_sealed_marker());
+            <Self as ::bevy_butler::__internal::ButlerPlugin>::register_butler_systems(#app_ident);
         ))
     };
 
@@ -121,7 +149,7 @@ pub(crate) fn impl_impl(_attr: ButlerPluginAttr, mut body: ItemImpl) -> syn::Res
 
     let plugin = &body.self_ty;
 
-    let register_block = register_butler_plugin_stmts(&syn::parse2(quote!(#plugin))?);
+    let register_block = register_butler_plugin_stmts(&attr, &syn::parse2(quote!(#plugin))?);
 
     Ok(quote! {
         #body
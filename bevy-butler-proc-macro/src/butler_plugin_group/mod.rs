@@ -4,6 +4,8 @@ use quote::quote;
 use structs::ButlerPluginGroupAttr;
 use syn::{Error, ImplItemFn, Item};
 
+use crate::utils::codegen_enabled;
+
 pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
@@ -12,8 +14,16 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
 
     let name_func: Option<ImplItemFn> = attr.name.map(|name| {
         syn::parse_quote! {
-            fn name() -> String {
-                #name.to_string()
+            fn name() -> ::bevy_butler::__internal::alloc::string::String {
+                ::core::convert::Into::into(#name)
+            }
+        }
+    });
+
+    let submit_codegen_entries = codegen_enabled().then(|| {
+        quote! {
+            fn submit_codegen_entries() {
+                ::bevy_butler::_butler_submit_codegen_entries!();
             }
         }
     });
@@ -25,20 +35,22 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
             Ok(quote! {
                 #i_struct
 
-                impl #ident {
-                    pub(crate) fn _butler_plugin_group_sealed_marker() -> ::std::any::TypeId {
+                impl ::bevy_butler::__internal::ButlerPluginGroupMarker for #ident {
+                    fn butler_plugin_group_marker() -> ::core::any::TypeId {
                         struct SealedMarker;
 
-                        std::any::TypeId::of::<SealedMarker>()
+                        ::core::any::TypeId::of::<SealedMarker>()
                     }
                 }
 
-                impl ::bevy_butler::__internal::ButlerPluginGroup for #ident {}
+                impl ::bevy_butler::__internal::ButlerPluginGroup for #ident {
+                    #submit_codegen_entries
+                }
 
                 impl ::bevy_butler::__internal::bevy_app::PluginGroup for #ident {
                     fn build(self) -> ::bevy_butler::__internal::bevy_app::PluginGroupBuilder {
                         <Self as ::bevy_butler::__internal::ButlerPluginGroup>
-                            ::register_plugins(::bevy_butler::__internal::bevy_app::PluginGroupBuilder::start::<Self>(), #ident::_butler_plugin_group_sealed_marker())
+                            ::register_plugins(::bevy_butler::__internal::bevy_app::PluginGroupBuilder::start::<Self>(), <Self as ::bevy_butler::__internal::ButlerPluginGroupMarker>::butler_plugin_group_marker(), ::core::option::Option::None)
                     }
 
                     #name_func
@@ -0,0 +1,52 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::ButlerTestAttr;
+use syn::{Error, ItemFn};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let attr: ButlerTestAttr = deluxe::parse(attr)?;
+    let mut test_fn: ItemFn = syn::parse(body)?;
+
+    let ident = &test_fn.sig.ident;
+    let call = match test_fn.sig.inputs.len() {
+        0 => quote!(#ident()),
+        1 => quote!(#ident(app.world_mut())),
+        _ => {
+            return Err(Error::new_spanned(
+                &test_fn.sig.inputs,
+                "Expected no arguments, or a single `&mut World` argument",
+            ))
+        }
+    };
+
+    // Attributes like `#[should_panic]` belong on the generated test
+    let attrs = std::mem::take(&mut test_fn.attrs);
+    let vis = &test_fn.vis;
+    let plugins = &attr.plugins;
+    let updates = match &attr.updates {
+        Some(updates) => quote!(::core::option::Option::Some(#updates)),
+        None => quote!(::core::option::Option::None),
+    };
+    let timeout = match &attr.timeout {
+        Some(timeout) => quote!(#timeout),
+        None => quote!(::bevy_butler::__internal::DEFAULT_TEST_TIMEOUT),
+    };
+
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #ident() {
+            #test_fn
+
+            ::bevy_butler::__internal::run_butler_test(#timeout, || {
+                let mut app = ::bevy_butler::__internal::bevy_app::App::new();
+                #(app.add_plugins(#plugins);)*
+                ::bevy_butler::__internal::update_butler_test(&mut app, #updates);
+                #call;
+            });
+        }
+    })
+}
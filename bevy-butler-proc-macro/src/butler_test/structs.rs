@@ -0,0 +1,10 @@
+use deluxe::ParseMetaItem;
+use syn::Expr;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ButlerTestAttr {
+    #[deluxe(default)]
+    pub plugins: Vec<Expr>,
+    pub updates: Option<Expr>,
+    pub timeout: Option<Expr>,
+}
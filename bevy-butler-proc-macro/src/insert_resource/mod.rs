@@ -1,42 +1,135 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use quote::{quote, ToTokens};
 use structs::*;
 use syn::Item;
 
-use crate::utils::{butler_plugin_entry_block, get_struct_or_enum_ident};
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident,
+    get_struct_or_enum_ident,
+};
 
 pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let attr: ResourceAttr = deluxe::parse(attr)?;
     let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("insert_resource", &args, &item)?;
     let res_ident = get_struct_or_enum_ident(&item)?;
 
     let plugin = &attr.plugin;
     let generics = &attr.generics;
 
-    let mut hash_bytes = res_ident.to_string();
-    hash_bytes += &plugin.to_token_stream().to_string();
-    hash_bytes += &generics.to_token_stream().to_string();
-    let static_ident = format_ident!("_butler_resource_{}", sha256::digest(hash_bytes));
-
-    let entry_expr = match (&attr.init, attr.non_send.is_set()) {
-        (Some(expr), false) => syn::parse_quote! {
-            |app| { app.insert_resource(#expr); }
-        },
-        (Some(expr), true) => syn::parse_quote! {
-            |app| { app.insert_non_send_resource(#expr); }
-        },
-        (None, false) => syn::parse_quote! {
-            |app| { app.init_resource::<#res_ident #generics>(); }
-        },
-        (None, true) => syn::parse_quote! {
-            |app| { app.init_non_send_resource::<#res_ident #generics>(); }
-        },
+    let static_ident = entry_static_ident("insert_resource", res_ident, &args);
+
+    let insert = |value: TokenStream2| match attr.non_send.is_set() {
+        false => quote!(app.insert_resource(#value);),
+        true => quote!(app.insert_non_send_resource(#value);),
+    };
+
+    let insert_stmt = match (&attr.init, &attr.init_with, attr.non_send.is_set()) {
+        (Some(expr), _, _) => insert(expr.to_token_stream()),
+        (None, Some(init_with), _) => {
+            let skip_on_error = attr.on_init_error.as_ref().is_some_and(|mode| mode == "skip");
+            let insert_initialized = insert(quote!(resource));
+            quote! {
+                if let ::core::option::Option::Some(resource) = ::bevy_butler::__internal::run_resource_init::<#plugin, #res_ident #generics, _>(
+                    app.world_mut(),
+                    #init_with,
+                    #skip_on_error,
+                ) {
+                    #insert_initialized
+                }
+            }
+        }
+        (None, None, false) => quote! { app.init_resource::<#res_ident #generics>(); },
+        (None, None, true) => quote! { app.init_non_send_resource::<#res_ident #generics>(); },
+    };
+
+    let load_file = match (&attr.from_file, &attr.persist) {
+        (Some(path), _) => Some((path, false)),
+        (None, Some(path)) => Some((path, true)),
+        (None, None) => None,
+    };
+
+    let insert_stmt = match load_file {
+        Some((path, persist)) => {
+            let insert_loaded = insert(quote!(resource));
+            quote! {
+                match ::bevy_butler::_butler_load_resource!(#res_ident #generics, #path, #persist) {
+                    Some(resource) => { #insert_loaded }
+                    None => { #insert_stmt }
+                }
+            }
+        }
+        None => insert_stmt,
+    };
+
+    let reflect_stmt = attr.reflect.is_set().then(|| {
+        quote! {
+            app.register_type::<#res_ident #generics>()
+                .register_type_data::<#res_ident #generics, ::bevy_butler::__internal::bevy_ecs::reflect::ReflectResource>();
+        }
+    });
+
+    let override_stmt = attr.env_prefix.as_ref().map(|prefix| {
+        let ignore_unknown = attr.env_ignore_unknown.is_set();
+        quote! {
+            ::bevy_butler::_butler_apply_resource_overrides!(app.world_mut(), #res_ident #generics, #prefix, #ignore_unknown);
+        }
+    });
+
+    let persist_stmt = attr.persist.as_ref().map(|path| {
+        let debounce = match &attr.persist_debounce {
+            Some(debounce) => quote!(::core::option::Option::Some(#debounce)),
+            None => quote!(::core::option::Option::None),
+        };
+        quote! {
+            ::bevy_butler::_butler_persist_resource!(app, #res_ident #generics, #path, #debounce);
+        }
+    });
+
+    let trace_stmt = attr.trace_changes.is_set().then(|| {
+        quote! {
+            if cfg!(debug_assertions) {
+                ::bevy_butler::__internal::add_resource_change_tracing::<#res_ident #generics>(app);
+            }
+        }
+    });
+
+    // A generic resource without `generics` has its type inferred from `init`, so it can't be named here
+    let named_type = generics.is_some()
+        || match &item {
+            Item::Struct(i) => i.generics.params.is_empty(),
+            Item::Enum(i) => i.generics.params.is_empty(),
+            _ => true,
+        };
+    let check_stmt = named_type.then(|| {
+        quote! {
+            if cfg!(debug_assertions) {
+                ::bevy_butler::__internal::check_duplicate_registration::<#plugin, #res_ident #generics>(app, "Resource");
+            }
+        }
+    });
+
+    let entry_expr = syn::parse_quote! {
+        |app| {
+            #check_stmt
+            #insert_stmt
+            #override_stmt
+            #reflect_stmt
+            #persist_stmt
+            #trace_stmt
+        }
     };
 
-    let register_block = butler_plugin_entry_block(&static_ident, &attr.plugin, &entry_expr);
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        &attr.plugin,
+        &entry_name(quote!(#res_ident #generics)),
+        &entry_expr,
+    );
 
     Ok(quote! {
         #item
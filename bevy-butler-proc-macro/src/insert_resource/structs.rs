@@ -1,10 +1,87 @@
 use deluxe::{Flag, ParseMetaItem};
-use syn::{AngleBracketedGenericArguments, Expr, Path};
+use syn::{AngleBracketedGenericArguments, Expr, Ident, LitStr, Path};
+
+fn validate(input: ResourceAttr) -> deluxe::Result<ResourceAttr> {
+    if let (true, Some(prefix)) = (input.non_send.is_set(), &input.env_prefix) {
+        return Err(deluxe::Error::new_spanned(
+            prefix,
+            "`env_prefix` cannot be used with `non_send`",
+        ));
+    }
+
+    if let (None, true) = (&input.env_prefix, input.env_ignore_unknown.is_set()) {
+        return Err(deluxe::Error::new_spanned(
+            input.env_ignore_unknown,
+            "`env_ignore_unknown` requires `env_prefix`",
+        ));
+    }
+
+    if let (true, true) = (input.non_send.is_set(), input.trace_changes.is_set()) {
+        return Err(deluxe::Error::new_spanned(
+            input.trace_changes,
+            "`trace_changes` cannot be used with `non_send`",
+        ));
+    }
+
+    if let (Some(_), Some(init_with)) = (&input.init, &input.init_with) {
+        return Err(deluxe::Error::new_spanned(
+            init_with,
+            "`init` and `init_with` cannot be used together",
+        ));
+    }
+
+    if let Some(mode) = &input.on_init_error {
+        if input.init_with.is_none() {
+            return Err(deluxe::Error::new_spanned(
+                mode,
+                "`on_init_error` requires `init_with`",
+            ));
+        }
+        if mode != "panic" && mode != "skip" {
+            return Err(deluxe::Error::new_spanned(
+                mode,
+                "expected `panic` or `skip`",
+            ));
+        }
+    }
+
+    if let Some(persist) = &input.persist {
+        if input.non_send.is_set() {
+            return Err(deluxe::Error::new_spanned(
+                persist,
+                "`persist` cannot be used with `non_send`",
+            ));
+        }
+        if input.from_file.is_some() {
+            return Err(deluxe::Error::new_spanned(
+                persist,
+                "`persist` cannot be used with `from_file`, as it already loads the resource from its file",
+            ));
+        }
+    } else if let Some(debounce) = &input.persist_debounce {
+        return Err(deluxe::Error::new_spanned(
+            debounce,
+            "`persist_debounce` requires `persist`",
+        ));
+    }
+
+    Ok(input)
+}
 
 #[derive(ParseMetaItem)]
+#[deluxe(and_then = validate)]
 pub(crate) struct ResourceAttr {
     pub plugin: Path,
     pub init: Option<Expr>,
+    pub init_with: Option<Expr>,
+    pub on_init_error: Option<Ident>,
     pub non_send: Flag,
     pub generics: Option<AngleBracketedGenericArguments>,
+    pub reflect: Flag,
+    pub from_file: Option<LitStr>,
+    pub env_prefix: Option<LitStr>,
+    pub env_ignore_unknown: Flag,
+    pub persist: Option<LitStr>,
+    pub persist_debounce: Option<Expr>,
+    pub trace_changes: Flag,
 }
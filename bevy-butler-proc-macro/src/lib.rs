@@ -5,68 +5,124 @@ pub(crate) mod utils;
 
 pub(crate) mod butler_plugin;
 
-fn result_to_tokens(result: syn::Result<TokenStream2>) -> TokenStream {
+/// Converts the expansion of a macro into tokens.
+///
+/// On error, `item` is emitted alongside the compile error, so that the annotated
+/// item stays visible to the rest of the crate (and to rust-analyzer).
+fn result_to_tokens(result: syn::Result<TokenStream2>, item: TokenStream) -> TokenStream {
     match result {
         Ok(tokens) => tokens.into(),
-        Err(e) => e.to_compile_error().into(),
+        Err(e) => {
+            let mut tokens: TokenStream = e.to_compile_error().into();
+            tokens.extend(item);
+            tokens
+        }
     }
 }
 
 #[proc_macro_attribute]
 pub fn butler_plugin(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(butler_plugin::macro_impl(attr, body))
+    result_to_tokens(butler_plugin::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod add_system;
 #[proc_macro_attribute]
 pub fn add_system(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(add_system::macro_impl(attr, body))
+    result_to_tokens(add_system::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod add_observer;
 #[proc_macro_attribute]
 pub fn add_observer(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(add_observer::macro_impl(attr, body))
+    result_to_tokens(add_observer::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod insert_resource;
 #[proc_macro_attribute]
 pub fn insert_resource(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(insert_resource::macro_impl(attr, body))
+    result_to_tokens(insert_resource::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod add_event;
 #[proc_macro_attribute]
 pub fn add_event(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(add_event::macro_impl(attr, body))
+    result_to_tokens(add_event::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod register_type;
 #[proc_macro_attribute]
 pub fn register_type(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(register_type::macro_impl(attr, body))
+    result_to_tokens(register_type::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod butler_plugin_group;
 #[proc_macro_attribute]
 pub fn butler_plugin_group(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(butler_plugin_group::macro_impl(attr, body))
+    result_to_tokens(butler_plugin_group::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod add_plugin;
 #[proc_macro_attribute]
 pub fn add_plugin(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(add_plugin::macro_impl(attr, body))
+    result_to_tokens(add_plugin::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod add_plugin_group;
 #[proc_macro_attribute]
 pub fn add_plugin_group(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(add_plugin_group::macro_impl(attr, body))
+    result_to_tokens(add_plugin_group::macro_impl(attr, body.clone()), body)
 }
 
 pub(crate) mod insert_state;
 #[proc_macro_attribute]
 pub fn insert_state(attr: TokenStream, body: TokenStream) -> TokenStream {
-    result_to_tokens(insert_state::macro_impl(attr, body))
+    result_to_tokens(insert_state::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod register_component;
+#[proc_macro_attribute]
+pub fn register_component(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_component::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod spawn;
+#[proc_macro_attribute]
+pub fn spawn(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(spawn::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod init_asset;
+#[proc_macro_attribute]
+pub fn init_asset(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(init_asset::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod register_asset_loader;
+#[proc_macro_attribute]
+pub fn register_asset_loader(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_asset_loader::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod register_asset_processor;
+#[proc_macro_attribute]
+pub fn register_asset_processor(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_asset_processor::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod register_asset_reflect;
+#[proc_macro_attribute]
+pub fn register_asset_reflect(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(register_asset_reflect::macro_impl(attr, body.clone()), body)
+}
+
+pub(crate) mod butler_chain;
+#[proc_macro]
+pub fn butler_chain(input: TokenStream) -> TokenStream {
+    result_to_tokens(butler_chain::macro_impl(input), TokenStream::new())
+}
+
+pub(crate) mod butler_test;
+#[proc_macro_attribute]
+pub fn butler_test(attr: TokenStream, body: TokenStream) -> TokenStream {
+    result_to_tokens(butler_test::macro_impl(attr, body.clone()), body)
 }
@@ -0,0 +1,54 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::AssetLoaderAttr;
+use syn::Item;
+
+use crate::utils::{
+    bevy_asset_path, butler_plugin_entry_block, check_duplicate_attr, entry_name,
+    entry_static_ident, get_struct_or_enum_ident,
+};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
+    let attr: AssetLoaderAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("register_asset_loader", &args, &item)?;
+    let loader_ident = get_struct_or_enum_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let generics = &attr.generics;
+
+    let static_ident = entry_static_ident("register_asset_loader", loader_ident, &args);
+
+    let bevy_asset = bevy_asset_path();
+    let register_stmt = match &attr.init {
+        Some(expr) => quote! {
+            #bevy_asset::AssetApp::register_asset_loader::<#loader_ident #generics>(app, #expr);
+        },
+        None => quote! {
+            #bevy_asset::AssetApp::init_asset_loader::<#loader_ident #generics>(app);
+        },
+    };
+
+    let entry_expr = syn::parse_quote! {
+        |app| {
+            #register_stmt
+        }
+    };
+
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        plugin,
+        &entry_name(quote!(#loader_ident #generics)),
+        &entry_expr,
+    );
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,9 @@
+use deluxe::ParseMetaItem;
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+#[derive(ParseMetaItem)]
+pub(crate) struct AssetLoaderAttr {
+    pub plugin: Path,
+    pub init: Option<Expr>,
+    pub generics: Option<AngleBracketedGenericArguments>,
+}
@@ -0,0 +1,61 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::AssetProcessorAttr;
+use syn::Item;
+
+use crate::utils::{
+    bevy_asset_path, butler_plugin_entry_block, check_duplicate_attr, entry_name,
+    entry_static_ident, get_struct_or_enum_ident,
+};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
+    let attr: AssetProcessorAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("register_asset_processor", &args, &item)?;
+    let processor_ident = get_struct_or_enum_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let generics = &attr.generics;
+
+    let static_ident = entry_static_ident("register_asset_processor", processor_ident, &args);
+
+    let bevy_asset = bevy_asset_path();
+    let processor = match &attr.init {
+        Some(expr) => quote!(#expr),
+        None => quote! {
+            <#processor_ident #generics as ::bevy_butler::__internal::bevy_ecs::world::FromWorld>::from_world(
+                app.world_mut()
+            )
+        },
+    };
+    let default_stmt = attr.default_extension.as_ref().map(|extension| {
+        quote! {
+            #bevy_asset::AssetApp::set_default_asset_processor::<#processor_ident #generics>(app, #extension);
+        }
+    });
+
+    let entry_expr = syn::parse_quote! {
+        |app| {
+            let processor = #processor;
+            #bevy_asset::AssetApp::register_asset_processor::<#processor_ident #generics>(app, processor);
+            #default_stmt
+        }
+    };
+
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        plugin,
+        &entry_name(quote!(#processor_ident #generics)),
+        &entry_expr,
+    );
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
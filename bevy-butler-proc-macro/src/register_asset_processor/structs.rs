@@ -0,0 +1,10 @@
+use deluxe::ParseMetaItem;
+use syn::{AngleBracketedGenericArguments, Expr, LitStr, Path};
+
+#[derive(ParseMetaItem)]
+pub(crate) struct AssetProcessorAttr {
+    pub plugin: Path,
+    pub init: Option<Expr>,
+    pub default_extension: Option<LitStr>,
+    pub generics: Option<AngleBracketedGenericArguments>,
+}
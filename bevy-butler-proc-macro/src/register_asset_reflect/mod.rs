@@ -0,0 +1,45 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::AssetReflectAttr;
+use syn::Item;
+
+use crate::utils::{
+    bevy_asset_path, butler_plugin_entry_block, check_duplicate_attr, entry_name,
+    entry_static_ident, get_struct_or_enum_ident,
+};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
+    let attr: AssetReflectAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("register_asset_reflect", &args, &item)?;
+    let asset_ident = get_struct_or_enum_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let generics = &attr.generics;
+
+    let static_ident = entry_static_ident("register_asset_reflect", asset_ident, &args);
+
+    let bevy_asset = bevy_asset_path();
+    let entry_expr = syn::parse_quote! {
+        |app| {
+            #bevy_asset::AssetApp::register_asset_reflect::<#asset_ident #generics>(app);
+        }
+    };
+
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        plugin,
+        &entry_name(quote!(#asset_ident #generics)),
+        &entry_expr,
+    );
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
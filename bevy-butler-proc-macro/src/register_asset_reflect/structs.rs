@@ -0,0 +1,8 @@
+use deluxe::ParseMetaItem;
+use syn::{AngleBracketedGenericArguments, Path};
+
+#[derive(ParseMetaItem)]
+pub(crate) struct AssetReflectAttr {
+    pub plugin: Path,
+    pub generics: Option<AngleBracketedGenericArguments>,
+}
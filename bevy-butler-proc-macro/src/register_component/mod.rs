@@ -0,0 +1,70 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use structs::ComponentAttr;
+use syn::Item;
+
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident,
+    get_struct_or_enum_ident,
+};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
+    let attr: ComponentAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("register_component", &args, &item)?;
+    let comp_ident = get_struct_or_enum_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let generics = &attr.generics;
+
+    let static_ident = entry_static_ident("register_component", comp_ident, &args);
+
+    let reflect_stmt = attr.reflect.is_set().then(|| {
+        quote! {
+            app.register_type::<#comp_ident #generics>()
+                .register_type_data::<#comp_ident #generics, ::bevy_butler::__internal::bevy_ecs::reflect::ReflectComponent>();
+        }
+    });
+
+    let hook_stmts = attr.hooks().map(|(name, hook)| {
+        let try_ident = format_ident!("try_{}", name);
+        let message = format!(
+            "{{}} already has an `{name}` hook. Remove either `{name}` from `#[register_component]` or the hook set by `#[component]`"
+        );
+        quote! {
+            if app.world_mut()
+                .register_component_hooks::<#comp_ident #generics>()
+                .#try_ident(#hook)
+                .is_none()
+            {
+                panic!(#message, ::core::any::type_name::<#comp_ident #generics>());
+            }
+        }
+    });
+
+    let requires = &attr.requires;
+
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        plugin,
+        &entry_name(quote!(#comp_ident #generics)),
+        &syn::parse_quote! {
+            |app| {
+                app.world_mut().register_component::<#comp_ident #generics>();
+                #reflect_stmt
+                #(#hook_stmts)*
+                #(app.register_required_components::<#comp_ident #generics, #requires>();)*
+            }
+        },
+    );
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
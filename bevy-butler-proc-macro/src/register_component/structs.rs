@@ -0,0 +1,31 @@
+use deluxe::{Flag, ParseMetaItem};
+use syn::{AngleBracketedGenericArguments, Expr, Path, Type};
+
+#[derive(ParseMetaItem)]
+pub(crate) struct ComponentAttr {
+    pub plugin: Path,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    pub reflect: Flag,
+    pub on_add: Option<Expr>,
+    pub on_insert: Option<Expr>,
+    pub on_replace: Option<Expr>,
+    pub on_remove: Option<Expr>,
+    pub on_despawn: Option<Expr>,
+    #[deluxe(default)]
+    pub requires: Vec<Type>,
+}
+
+impl ComponentAttr {
+    /// Returns each hook that was set, paired with the name of its `ComponentHooks` setter
+    pub fn hooks(&self) -> impl Iterator<Item = (&'static str, &Expr)> {
+        [
+            ("on_add", &self.on_add),
+            ("on_insert", &self.on_insert),
+            ("on_replace", &self.on_replace),
+            ("on_remove", &self.on_remove),
+            ("on_despawn", &self.on_despawn),
+        ]
+        .into_iter()
+        .filter_map(|(name, hook)| hook.as_ref().map(|hook| (name, hook)))
+    }
+}
@@ -1,16 +1,20 @@
 use proc_macro::TokenStream as TokenStream1;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote};
+use quote::quote;
 use structs::RegisterTypeAttr;
 use syn::{Error, Item};
 
-use crate::utils::{butler_plugin_entry_block, get_use_path};
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident, get_use_path,
+};
 
 pub(crate) mod structs;
 
 pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
     let attr: RegisterTypeAttr = deluxe::parse(attr)?;
     let item: Item = syn::parse(body)?;
+    check_duplicate_attr("register_type", &args, &item)?;
     let type_ident = match &item {
         Item::Struct(i_struct) => &i_struct.ident,
         Item::Use(i_use) => get_use_path(&i_use.tree)?,
@@ -27,7 +31,7 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
     let plugin = &attr.plugin;
     let type_data = &attr.type_data;
 
-    let static_ident = format_ident!("_butler_typereg_{}", sha256::digest(type_ident.to_string()));
+    let static_ident = entry_static_ident("register_type", type_ident, &args);
     let entry_expr = syn::parse_quote! {
         |app| {
             app.register_type::<#type_ident>()#(
@@ -35,7 +39,7 @@ pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<
         }
     };
 
-    let register_block = butler_plugin_entry_block(&static_ident, plugin, &entry_expr);
+    let register_block = butler_plugin_entry_block(&static_ident, plugin, &entry_name(type_ident), &entry_expr);
 
     Ok(quote! {
         #item
@@ -0,0 +1,60 @@
+use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use structs::SpawnAttr;
+use syn::{Expr, Item};
+
+use crate::utils::{
+    butler_plugin_entry_block, check_duplicate_attr, entry_name, entry_static_ident, get_fn_ident,
+};
+
+pub(crate) mod structs;
+
+pub(crate) fn macro_impl(attr: TokenStream1, body: TokenStream1) -> syn::Result<TokenStream2> {
+    let args = TokenStream2::from(attr.clone());
+    let attr: SpawnAttr = deluxe::parse(attr)?;
+    let item = syn::parse::<Item>(body)?;
+    check_duplicate_attr("spawn", &args, &item)?;
+    let ident = get_fn_ident(&item)?;
+
+    let plugin = &attr.plugin;
+    let schedule = &attr.schedule;
+    let generics = attr.generics.clone().map(|mut g| {
+        g.colon2_token = Some(Default::default());
+        g
+    });
+    let transforms = &attr.transforms.0;
+
+    let name = match attr.name.is_set() {
+        true => {
+            let name = ident.to_string();
+            quote!(::core::option::Option::Some(#name))
+        }
+        false => quote!(::core::option::Option::None),
+    };
+    let marker = attr.marker.is_set();
+
+    let sys_expr: Expr = syn::parse_quote! {
+        ::bevy_butler::__internal::bevy_ecs::system::IntoSystem::pipe(
+            #ident #generics,
+            ::bevy_butler::__internal::spawn_bundles::<#plugin, _, _>(#name, #marker),
+        ) #(. #transforms)*
+    };
+
+    let static_ident = entry_static_ident("spawn", ident, &args);
+
+    let register_block = butler_plugin_entry_block(
+        &static_ident,
+        plugin,
+        &entry_name(quote!(#ident #generics)),
+        &syn::parse_quote! {
+            |app| { app.add_systems( #schedule, #sys_expr ); }
+        },
+    );
+
+    Ok(quote! {
+        #item
+
+        #register_block
+    })
+}
@@ -0,0 +1,15 @@
+use deluxe::{Flag, ParseMetaItem};
+use syn::{AngleBracketedGenericArguments, Expr, Path};
+
+use crate::add_system::structs::TransformList;
+
+#[derive(ParseMetaItem)]
+pub(crate) struct SpawnAttr {
+    pub plugin: Path,
+    pub schedule: Expr,
+    pub generics: Option<AngleBracketedGenericArguments>,
+    pub marker: Flag,
+    pub name: Flag,
+    #[deluxe(rest)]
+    pub transforms: TransformList,
+}
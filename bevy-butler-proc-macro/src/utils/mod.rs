@@ -1,33 +1,151 @@
+use std::fmt::Display;
+
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Error, ExprClosure, Ident, Item, Path, UseTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Attribute, Error, ExprClosure, Ident, Item, Meta, Path, UseTree};
 
+/// Registers `expr` to run when `plugin` is built.
+///
+/// `name` identifies the annotated item within its module. The full entry identifier
+/// is `module_path!()::name`, which is what [`ButlerFilter`] patterns are matched against.
 pub(crate) fn butler_plugin_entry_block(
     static_ident: &Ident,
     plugin: &Path,
+    name: &str,
     expr: &ExprClosure,
 ) -> TokenStream {
-    quote! {
-        ::bevy_butler::_butler_plugin_entry!(#static_ident, ::bevy_butler::__internal::ButlerPluginRegistryEntryFactory::new(
-            || #plugin::_butler_plugin_sealed_marker(),
+    let entry = quote! {
+        ::bevy_butler::__internal::ButlerPluginRegistryEntryFactory::new(
+            <#plugin as ::bevy_butler::__internal::ButlerPluginMarker>::MARKER,
+            ::core::concat!(::core::module_path!(), "::", #name),
             #expr
-        ));
+        )
+    };
+
+    match codegen_enabled() {
+        true => quote!(::bevy_butler::_butler_codegen_entry!(#static_ident, #entry);),
+        false => quote!(::bevy_butler::_butler_plugin_entry!(#static_ident, #entry);),
+    }
+}
+
+/// Whether the crate being compiled registers its entries through the table that
+/// `bevy_butler_build::generate()` generated for it, instead of the default backend.
+///
+/// The build script names the crate it scanned in `BEVY_BUTLER_CODEGEN`, which cargo sets for
+/// every target of the package, so it has to be compared with the crate that is being compiled.
+pub(crate) fn codegen_enabled() -> bool {
+    let Ok(target) = std::env::var("BEVY_BUTLER_CODEGEN") else {
+        return false;
+    };
+    // Doctests are compiled with the environment of their library, but aren't part of it
+    if std::env::var_os("UNSTABLE_RUSTDOC_TEST_PATH").is_some() {
+        return false;
+    }
+
+    let kind = match std::env::var_os("CARGO_BIN_NAME") {
+        Some(_) => "bin",
+        None => "lib",
+    };
+    let krate = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    target == format!("{kind}:{krate}")
+}
+
+/// The name of the static holding a registry entry.
+///
+/// An entry is identified by the name of the macro, the annotated `item` and the macro's `args`,
+/// so two attributes only share a static if they are exact duplicates, which are reported
+/// by [`check_duplicate_attr`] beforehand.
+///
+/// Whitespace is ignored, so that `bevy-butler-build` can derive the same name from the
+/// source files. Both implementations need to be kept in sync.
+pub(crate) fn entry_static_ident(
+    macro_name: &str,
+    item: impl Display,
+    args: &TokenStream,
+) -> Ident {
+    let args: String = args.to_string().split_whitespace().collect();
+    let hash = sha256::digest(format!("{macro_name}:{item}:{args}"));
+    format_ident!("_butler_{macro_name}_{hash}")
+}
+
+/// Rejects `item` if another `#[macro_name]` attribute on it has the same `args`.
+///
+/// Only the attributes after the one being expanded are visible, which is enough
+/// to catch every duplicate once.
+pub(crate) fn check_duplicate_attr(
+    macro_name: &str,
+    args: &TokenStream,
+    item: &Item,
+) -> syn::Result<()> {
+    let attrs: &[Attribute] = match item {
+        Item::Fn(i) => &i.attrs,
+        Item::Struct(i) => &i.attrs,
+        Item::Enum(i) => &i.attrs,
+        Item::Use(i) => &i.attrs,
+        Item::Type(i) => &i.attrs,
+        _ => return Ok(()),
+    };
+
+    let args = args.to_string();
+    for attr in attrs {
+        if attr.path().segments.last().is_none_or(|s| s.ident != macro_name) {
+            continue;
+        }
+
+        let other_args = match &attr.meta {
+            Meta::List(list) => list.tokens.to_string(),
+            Meta::Path(_) => String::new(),
+            Meta::NameValue(_) => continue,
+        };
+        if other_args == args {
+            return Err(Error::new_spanned(
+                attr,
+                format!(
+                    "duplicate `#[{macro_name}]` attribute, which would register the same entry twice"
+                ),
+            ));
+        }
     }
+
+    Ok(())
 }
 
+/// The name of an annotated item for its entry identifier, like `my_system::<u32>`.
+pub(crate) fn entry_name(item: impl ToTokens) -> String {
+    item.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Registers `expr` to run when the plugin group `plugin` is built.
+///
+/// `name` identifies the annotated item like in [`butler_plugin_entry_block`].
 pub(crate) fn butler_plugin_group_entry_block(
     static_ident: &Ident,
     plugin: &Path,
+    name: &str,
     expr: &ExprClosure,
 ) -> TokenStream {
-    quote! {
-        ::bevy_butler::_butler_plugin_group_entry!(#static_ident, ::bevy_butler::__internal::ButlerPluginGroupRegistryEntryFactory {
-            type_factory: || #plugin::_butler_plugin_group_sealed_marker(),
+    let entry = quote! {
+        ::bevy_butler::__internal::ButlerPluginGroupRegistryEntryFactory {
+            type_factory: <#plugin as ::bevy_butler::__internal::ButlerPluginGroupMarker>::butler_plugin_group_marker,
+            id: ::core::concat!(::core::module_path!(), "::", #name),
             group_factory: #expr
-        });
+        }
+    };
+
+    match codegen_enabled() {
+        true => quote!(::bevy_butler::_butler_codegen_group_entry!(#static_ident, #entry);),
+        false => quote!(::bevy_butler::_butler_plugin_group_entry!(#static_ident, #entry);),
     }
 }
 
+/// The path to `bevy_asset` in the calling crate, either `bevy::asset` or `bevy_asset`.
+///
+/// bevy-butler doesn't depend on `bevy_asset`, so asset registration goes through
+/// whichever one the user depends on.
+pub(crate) fn bevy_asset_path() -> Path {
+    bevy_macro_utils::BevyManifest::shared().get_path("bevy_asset")
+}
+
 pub(crate) fn get_use_path(tree: &UseTree) -> syn::Result<&Ident> {
     match tree {
         UseTree::Path(path) => get_use_path(&path.tree),
@@ -0,0 +1,68 @@
+//! Measures how long an app takes to build every butler plugin of the binary at startup.
+//!
+//! The first build includes any one-time setup of the backend, like grouping every entry into a
+//! registry, so it's reported separately from the builds after it.
+//!
+//! Run with `cargo bench -p bevy-butler --bench registry`, and compare with
+//! `cargo bench -p bevy-butler --bench registry --features inventory`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bevy_app::App;
+
+macro_rules! systems {
+    ($($system:ident)*) => {
+        $(
+            #[add_system(plugin = BenchPlugin, schedule = Update)]
+            fn $system() {}
+        )*
+    };
+}
+
+macro_rules! plugins {
+    ($($plugin:ident)*) => {
+        $(
+            mod $plugin {
+                use bevy_app::Update;
+                use bevy_butler::*;
+
+                #[butler_plugin]
+                pub struct BenchPlugin;
+
+                systems!(
+                    s00 s01 s02 s03 s04 s05 s06 s07 s08 s09 s10 s11 s12 s13 s14 s15
+                    s16 s17 s18 s19 s20 s21 s22 s23 s24 s25 s26 s27 s28 s29 s30 s31
+                );
+            }
+        )*
+
+        const PLUGINS: usize = [$(stringify!($plugin)),*].len();
+
+        fn build_app() -> App {
+            let mut app = App::new();
+            $(app.add_plugins($plugin::BenchPlugin);)*
+            app
+        }
+    };
+}
+
+plugins!(
+    p00 p01 p02 p03 p04 p05 p06 p07 p08 p09 p10 p11 p12 p13 p14 p15
+    p16 p17 p18 p19 p20 p21 p22 p23 p24 p25 p26 p27 p28 p29 p30 p31
+);
+
+const ITERATIONS: u32 = 100;
+
+fn main() {
+    let start = Instant::now();
+    black_box(build_app());
+    println!("first build of {PLUGINS} plugins: {:?}", start.elapsed());
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(build_app());
+    }
+    let elapsed: Duration = start.elapsed() / ITERATIONS;
+    println!("later builds of {PLUGINS} plugins: {elapsed:?}");
+}
@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use bevy_platform::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, Mutex},
+};
+use core::any::TypeId;
+
+use super::{ButlerPluginGroupRegistryEntryFactory, ButlerPluginRegistryEntryFactory};
+
+/// The entries of a crate, collected by the table that `bevy-butler-build` generates.
+#[derive(Default)]
+pub struct CodegenEntries {
+    plugins: Vec<&'static ButlerPluginRegistryEntryFactory>,
+    groups: Vec<&'static ButlerPluginGroupRegistryEntryFactory>,
+    tables: Vec<CodegenTable>,
+}
+
+impl CodegenEntries {
+    pub fn add<E: CodegenEntry>(&mut self, entry: &'static E) {
+        entry.add_to(self);
+    }
+
+    /// Adds the table of another crate, which is submitted along with this one.
+    pub fn add_table(&mut self, table: CodegenTable) {
+        self.tables.push(table);
+    }
+}
+
+/// An entry static that can be added to [`CodegenEntries`].
+pub trait CodegenEntry {
+    fn add_to(&'static self, entries: &mut CodegenEntries);
+}
+
+impl CodegenEntry for ButlerPluginRegistryEntryFactory {
+    fn add_to(&'static self, entries: &mut CodegenEntries) {
+        entries.plugins.push(self);
+    }
+}
+
+impl CodegenEntry for ButlerPluginGroupRegistryEntryFactory {
+    fn add_to(&'static self, entries: &mut CodegenEntries) {
+        entries.groups.push(self);
+    }
+}
+
+pub type CodegenTable = fn(&mut CodegenEntries);
+
+/// The entries of every table submitted so far, grouped by their plugin or plugin group.
+#[derive(Default)]
+struct CodegenRegistry {
+    tables: HashSet<usize>,
+    plugins: HashMap<u64, Vec<ButlerPluginRegistryEntryFactory>>,
+    groups: HashMap<TypeId, Vec<ButlerPluginGroupRegistryEntryFactory>>,
+    /// The plugins that were already built, which can't get new entries.
+    built_plugins: HashSet<u64>,
+    /// The plugin groups that were already built, which can't get new entries.
+    built_groups: HashSet<TypeId>,
+}
+
+static REGISTRY: LazyLock<Mutex<CodegenRegistry>> = LazyLock::new(Default::default);
+
+/// Adds the entries of a generated table, and of the tables that it includes, to the registry.
+/// Tables that were submitted before are skipped.
+///
+/// # Panics
+/// Panics if one of the new entries is added to a plugin or plugin group that was already built,
+/// since it would never be registered.
+pub fn submit_codegen_entries(table: CodegenTable) {
+    let mut late = None;
+    {
+        let mut registry = REGISTRY.lock().unwrap();
+        let mut pending = alloc::vec![table];
+        while let Some(table) = pending.pop() {
+            if !registry.tables.insert(table as usize) {
+                continue;
+            }
+
+            let mut entries = CodegenEntries::default();
+            table(&mut entries);
+            pending.append(&mut entries.tables);
+
+            for factory in entries.plugins {
+                let marker = factory.plugin();
+                if registry.built_plugins.contains(&marker) {
+                    late.get_or_insert(factory.id());
+                }
+                registry.plugins.entry(marker).or_default().push(*factory);
+            }
+            for factory in entries.groups {
+                let marker = (factory.type_factory)();
+                if registry.built_groups.contains(&marker) {
+                    late.get_or_insert(factory.id);
+                }
+                registry.groups.entry(marker).or_default().push(*factory);
+            }
+        }
+    }
+
+    if let Some(entry) = late {
+        panic!(
+            "{entry} was submitted after the plugin it's added to was built. \
+            Call `bevy_butler::submit_butler_entries!()` before adding any plugins, \
+            and include the tables of other crates with `include_butler_entries!(other_crate)`"
+        );
+    }
+}
+
+/// The entries added to the butler plugin `marker`, which can't get new entries afterwards.
+pub(crate) fn butler_plugin_factories(marker: u64) -> Vec<ButlerPluginRegistryEntryFactory> {
+    let mut registry = REGISTRY.lock().unwrap();
+    registry.built_plugins.insert(marker);
+    registry.plugins.get(&marker).cloned().unwrap_or_default()
+}
+
+/// The entries added to the butler plugin group `marker`, which can't get new entries afterwards.
+pub(crate) fn butler_plugin_group_factories(
+    marker: TypeId,
+) -> Vec<ButlerPluginGroupRegistryEntryFactory> {
+    let mut registry = REGISTRY.lock().unwrap();
+    registry.built_groups.insert(marker);
+    registry.groups.get(&marker).cloned().unwrap_or_default()
+}
+
+/// Fails to compile if the entry `name` of `module` is missing from the table of its crate.
+///
+/// `bevy-butler-build` finds entries by scanning the source files of the crate, so it misses the
+/// ones generated by other macros, or declared inside function bodies.
+pub const fn check_codegen_entry(table: &[&str], module: &str, name: &str) {
+    // `module_path!()` starts with the name of the crate, which the table leaves out
+    let module = module.as_bytes();
+    let mut start = 0;
+    while start < module.len() && module[start] != b':' {
+        start += 1;
+    }
+    if start < module.len() {
+        start += 2;
+    }
+
+    let mut i = 0;
+    while i < table.len() {
+        if is_entry(table[i].as_bytes(), module, start, name.as_bytes()) {
+            return;
+        }
+        i += 1;
+    }
+
+    panic!(
+        "this butler entry is missing from the table generated by bevy-butler-build. \
+        Entries generated by other macros or inside function bodies can't be found by the build script"
+    );
+}
+
+/// Whether `entry` is `name` in the module `module[start..]`.
+const fn is_entry(entry: &[u8], module: &[u8], start: usize, name: &[u8]) -> bool {
+    let module_len = module.len() - start;
+    let prefix = match module_len {
+        0 => 0,
+        len => len + 2,
+    };
+    if entry.len() != prefix + name.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < entry.len() {
+        let expected = match i {
+            i if i < module_len => module[start + i],
+            i if i < prefix => b':',
+            i => name[i - prefix],
+        };
+        if entry[i] != expected {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,43 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::PartialReflect;
+use core::any::type_name;
+
+use crate::logging;
+
+/// Logs every event of type `E` using its reflected representation.
+pub fn log_events<E: Event + PartialReflect>(mut events: EventReader<E>) {
+    for event in events.read() {
+        logging::debug!("{}: {:?}", type_name::<E>(), event as &dyn PartialReflect);
+    }
+}
+
+/// Adds an [`EventRecorder<E>`](crate::record::EventRecorder) and the systems for recording and replaying `E`.
+#[cfg(feature = "record")]
+pub fn add_event_recorder<E: Event + Clone>(app: &mut bevy_app::App, capacity: usize) {
+    use crate::record::*;
+
+    app.insert_resource(EventRecorder::<E>::new(capacity))
+        .add_systems(
+            bevy_app::First,
+            replay_events::<E>.run_if(resource_exists::<EventReplay<E>>),
+        )
+        .add_systems(bevy_app::Last, record_events::<E>);
+}
+
+#[cfg(feature = "record")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_record_events {
+    ($app:expr, $event:ty, $capacity:expr) => {
+        ::bevy_butler::__internal::add_event_recorder::<$event>($app, $capacity)
+    };
+}
+
+#[cfg(not(feature = "record"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_record_events {
+    ($app:expr, $event:ty, $capacity:expr) => {
+        ::core::compile_error!("`record` requires the `record` feature of bevy-butler")
+    };
+}
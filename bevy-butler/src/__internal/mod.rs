@@ -3,13 +3,43 @@ pub use inventory;
 #[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
 pub use linkme;
 
+pub extern crate alloc;
 pub use bevy_app;
 pub use bevy_ecs;
+#[cfg(feature = "std")]
 pub use bevy_log;
+pub use bevy_reflect;
 pub use bevy_state;
+pub use bevy_time;
 
 mod plugin;
 pub use plugin::*;
 
 mod plugin_group;
 pub use plugin_group::*;
+
+#[cfg(feature = "codegen")]
+mod codegen;
+#[cfg(feature = "codegen")]
+pub use codegen::*;
+
+mod observer;
+pub use observer::*;
+
+mod event;
+pub use event::*;
+
+mod registration;
+pub use registration::*;
+
+mod resource;
+pub use resource::*;
+
+mod spawn;
+pub use spawn::*;
+
+mod system;
+pub use system::*;
+
+mod test;
+pub use test::*;
@@ -0,0 +1,130 @@
+use alloc::{format, vec::Vec};
+use bevy_app::App;
+use bevy_ecs::{name::Name, prelude::*, system::IntoObserverSystem};
+use core::{any::TypeId, marker::PhantomData};
+
+use crate::ButlerObserver;
+
+/// Tracks the observers that butler attached to an entity because it gained `C`.
+///
+/// Each observer is stored along with the registration that attached it, so that adding `C` again
+/// doesn't attach a second one. The observers of registrations with `despawn_with_component` are
+/// despawned once `C` is removed, while the others stay attached.
+#[derive(Component)]
+pub struct ButlerComponentObservers<C: Component> {
+    pub observers: Vec<(TypeId, Entity)>,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C: Component> Default for ButlerComponentObservers<C> {
+    fn default() -> Self {
+        Self {
+            observers: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Attaches `observer` to every entity that gains the component `C`.
+///
+/// Each attached observer is spawned with `name` and a [`ButlerObserver<P>`] marker. So are the
+/// global observers that attach and detach it, so that [`ButlerObserver::despawn_all`] also stops
+/// new entities from getting the observer.
+///
+/// If `despawn_with_component` is set, the attached observer is despawned when `C` is removed
+/// from the entity. Otherwise, it lives until the entity is despawned.
+pub fn add_entity_observer<P, C, E, B, M, S>(
+    app: &mut App,
+    observer: S,
+    name: Name,
+    despawn_with_component: bool,
+) where
+    P: 'static,
+    C: Component,
+    E: Event,
+    B: Bundle,
+    S: IntoObserverSystem<E, B, M> + Clone + Send + Sync + 'static,
+{
+    let attach_name = Name::new(format!("{name} (attach)"));
+    let detach_name = Name::new(format!("{name} (detach)"));
+
+    let attach = move |trigger: Trigger<OnAdd, C>, mut commands: Commands| {
+        let target = trigger.target();
+        let observer = observer.clone();
+        let name = name.clone();
+        commands.queue(move |world: &mut World| {
+            // The component may have been removed before this command was applied
+            if !world.get_entity(target).is_ok_and(|e| e.contains::<C>()) {
+                return;
+            }
+
+            // Without `despawn_with_component`, the observer from an earlier insert is still attached
+            let attached = world
+                .get::<ButlerComponentObservers<C>>(target)
+                .is_some_and(|tracked| {
+                    tracked.observers.iter().any(|&(registration, observer)| {
+                        registration == TypeId::of::<(P, S)>() && world.get_entity(observer).is_ok()
+                    })
+                });
+            if attached {
+                return;
+            }
+
+            let observer_entity = world
+                .spawn((
+                    Observer::new(observer).with_entity(target),
+                    name,
+                    ButlerObserver::<P>::default(),
+                ))
+                .id();
+            world
+                .entity_mut(target)
+                .entry::<ButlerComponentObservers<C>>()
+                .or_default()
+                .get_mut()
+                .observers
+                .push((TypeId::of::<(P, S)>(), observer_entity));
+        });
+    };
+    app.world_mut().spawn((
+        Observer::new(attach),
+        attach_name,
+        ButlerObserver::<P>::default(),
+    ));
+
+    if despawn_with_component {
+        // Only the observers of this registration opted in to being despawned with `C`
+        let detach = |trigger: Trigger<OnRemove, C>, mut commands: Commands| {
+            let target = trigger.target();
+            commands.queue(move |world: &mut World| {
+                let Ok(mut entity) = world.get_entity_mut(target) else {
+                    return;
+                };
+                let Some(mut tracked) = entity.get_mut::<ButlerComponentObservers<C>>() else {
+                    return;
+                };
+
+                let mut detached = Vec::new();
+                tracked.observers.retain(|&(registration, observer)| {
+                    let matches = registration == TypeId::of::<(P, S)>();
+                    if matches {
+                        detached.push(observer);
+                    }
+                    !matches
+                });
+                if tracked.observers.is_empty() {
+                    entity.remove::<ButlerComponentObservers<C>>();
+                }
+
+                for observer in detached {
+                    world.try_despawn(observer).ok();
+                }
+            });
+        };
+        app.world_mut().spawn((
+            Observer::new(detach),
+            detach_name,
+            ButlerObserver::<P>::default(),
+        ));
+    }
+}
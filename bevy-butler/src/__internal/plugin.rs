@@ -1,29 +1,77 @@
+use alloc::vec::Vec;
 use bevy_app::{App, Plugin};
-use std::{
-    any::{type_name, TypeId},
-    collections::HashMap,
-    sync::LazyLock,
-};
+use bevy_platform::sync::LazyLock;
+use core::any::type_name;
 
-pub struct ButlerPluginRegistryEntryFactory(fn() -> TypeId, fn(&mut bevy_app::App));
+use crate::{logging, ButlerFilter};
+
+#[derive(Clone, Copy)]
+pub struct ButlerPluginRegistryEntryFactory(u64, &'static str, fn(&mut bevy_app::App));
 
 impl ButlerPluginRegistryEntryFactory {
-    pub const fn new(type_factory: fn() -> TypeId, sys_factory: fn(&mut bevy_app::App)) -> Self {
-        ButlerPluginRegistryEntryFactory(type_factory, sys_factory)
+    pub const fn new(
+        marker: u64,
+        id: &'static str,
+        sys_factory: fn(&mut bevy_app::App),
+    ) -> Self {
+        ButlerPluginRegistryEntryFactory(marker, id, sys_factory)
+    }
+
+    /// The marker of the plugin that this entry is registered to.
+    #[cfg(feature = "codegen")]
+    pub(crate) fn plugin(&self) -> u64 {
+        self.0
+    }
+
+    #[cfg(feature = "codegen")]
+    pub(crate) fn id(&self) -> &'static str {
+        self.1
     }
 }
 
-pub struct ButlerPluginRegistry(HashMap<TypeId, Vec<fn(&mut App)>>);
+/// The marker of a butler plugin, hashed from `path` with FNV-1a.
+///
+/// `#[butler_plugin]` passes where it's expanded and the plugin type, so that every plugin of the
+/// binary gets its own marker without calling a function for it, and entries can be sorted by it.
+pub const fn butler_plugin_marker(path: &str) -> u64 {
+    let bytes = path.as_bytes();
+    let mut hash = 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
+/// Every entry of the binary, sorted by the plugin that they are registered to.
+///
+/// Sorting only reads the marker stored in each entry, so building the registry doesn't call
+/// into any entry, and building a plugin only looks up its own entries.
+pub(crate) struct ButlerPluginRegistry(Vec<ButlerPluginRegistryEntryFactory>);
 
 impl ButlerPluginRegistry {
+    /// Sorts `factories` by the plugin that they are registered to.
+    pub(crate) fn from_factories<'a>(
+        factories: impl IntoIterator<Item = &'a ButlerPluginRegistryEntryFactory>,
+    ) -> Self {
+        let mut registry: Vec<_> = factories.into_iter().copied().collect();
+        // A stable sort keeps the entries of each plugin in the order they were collected
+        registry.sort_by_key(|factory| factory.0);
+
+        logging::debug!("Building ButlerRegistry from {} entries", registry.len());
+
+        ButlerPluginRegistry(registry)
+    }
+
     pub(crate) fn get_system_factories(
         &'static self,
-        marker: TypeId,
-    ) -> &'static [fn(&mut bevy_app::App)] {
-        self.0
-            .get(&marker)
-            .map(|v| v.as_slice())
-            .unwrap_or_default()
+        marker: u64,
+    ) -> &'static [ButlerPluginRegistryEntryFactory] {
+        let start = self.0.partition_point(|factory| factory.0 < marker);
+        let end = start + self.0[start..].partition_point(|factory| factory.0 == marker);
+        &self.0[start..end]
     }
 }
 
@@ -34,43 +82,60 @@ pub static BUTLER_SLICE: [ButlerPluginRegistryEntryFactory] = [..];
 #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
 ::inventory::collect!(ButlerPluginRegistryEntryFactory);
 
-pub static BUTLER_PLUGIN_REGISTRY: LazyLock<ButlerPluginRegistry> = LazyLock::new(|| {
-    #[cfg(target_arch = "wasm32")]
+static BUTLER_PLUGIN_REGISTRY: LazyLock<ButlerPluginRegistry> = LazyLock::new(|| {
+    #[cfg(all(target_arch = "wasm32", feature = "wasm-experimental"))]
     crate::_initialize();
 
     #[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
-    let iter = BUTLER_SLICE.into_iter();
+    let factories = BUTLER_SLICE.iter();
     #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
-    let iter = ::inventory::iter::<ButlerPluginRegistryEntryFactory>.into_iter();
-
-    let mut count = 0;
-    let mut registry: HashMap<TypeId, Vec<fn(&mut App)>> = HashMap::new();
-    iter.for_each(
-        |ButlerPluginRegistryEntryFactory(type_factory, sys_factory)| {
-            registry
-                .entry(type_factory())
-                .or_default()
-                .push(*sys_factory);
-            count += 1;
-        },
-    );
-
-    // Trim down
-    registry.values_mut().for_each(|vec| vec.shrink_to_fit());
-    registry.shrink_to_fit();
-
-    bevy_log::debug!("Building ButlerRegistry from {count} entries");
-
-    ButlerPluginRegistry(registry)
+    let factories = ::inventory::iter::<ButlerPluginRegistryEntryFactory>;
+
+    ButlerPluginRegistry::from_factories(factories)
 });
 
-pub trait ButlerPlugin: Plugin {
-    fn register_butler_systems(app: &mut App, marker: TypeId) {
-        let factories = BUTLER_PLUGIN_REGISTRY.get_system_factories(marker);
-        for system_factory in factories {
-            system_factory(app);
+/// Implemented by `#[butler_plugin]`, and required of every `plugin = ...` target.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a #[butler_plugin]",
+    label = "not a #[butler_plugin]",
+    note = "add #[butler_plugin] to `{Self}`"
+)]
+pub trait ButlerPluginMarker: 'static {
+    /// Set by `#[butler_plugin(instrument)]` to instrument every system of the plugin.
+    const INSTRUMENT: bool = false;
+
+    /// The marker that entries of this plugin are registered under, made by
+    /// [`butler_plugin_marker`].
+    const MARKER: u64;
+
+    /// The entries registered to this plugin, looked up by its marker in the registry.
+    fn butler_plugin_entries() -> &'static [ButlerPluginRegistryEntryFactory] {
+        BUTLER_PLUGIN_REGISTRY.get_system_factories(Self::MARKER)
+    }
+}
+
+pub trait ButlerPlugin: Plugin + ButlerPluginMarker {
+    /// Submits the entries of the plugin's crate to the registry, when its entries
+    /// are registered through `bevy-butler-build`.
+    fn submit_codegen_entries() {}
+
+    fn register_butler_systems(app: &mut App) {
+        Self::submit_codegen_entries();
+        let factories = Self::butler_plugin_entries().iter().copied();
+        #[cfg(feature = "codegen")]
+        let factories = factories.chain(super::codegen::butler_plugin_factories(Self::MARKER));
+        let filter = app.world().get_resource::<ButlerFilter>().cloned();
+
+        let mut ran = 0;
+        for ButlerPluginRegistryEntryFactory(_, id, factory) in factories {
+            if filter.as_ref().is_some_and(|filter| !filter.is_allowed(id)) {
+                logging::debug!("{} skipped {} due to ButlerFilter", type_name::<Self>(), id);
+                continue;
+            }
+            factory(app);
+            ran += 1;
         }
-        bevy_log::debug!("{} ran {} factories", type_name::<Self>(), factories.len());
+        logging::debug!("{} ran {} factories", type_name::<Self>(), ran);
     }
 }
 
@@ -88,7 +153,10 @@ macro_rules! _butler_plugin_entry {
     };
 }
 
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+#[cfg(any(
+    all(target_arch = "wasm32", feature = "wasm-experimental"),
+    all(not(target_arch = "wasm32"), feature = "inventory")
+))]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! _butler_plugin_entry {
@@ -96,3 +164,63 @@ macro_rules! _butler_plugin_entry {
         ::bevy_butler::__internal::inventory::submit!($entry);
     };
 }
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm-experimental")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_plugin_entry {
+    ($static_ident:ident, $entry:expr) => {
+        ::core::compile_error!(
+            "WebAssembly support in bevy-butler is experimental. Register the entries of this crate \
+            through `bevy-butler-build` instead, or enable the `wasm-experimental` feature"
+        );
+    };
+}
+
+// The table generated by `bevy-butler-build` refers to the entry by name from the crate root.
+// It also lists the names of its entries, so that the ones the build script missed are reported
+#[cfg(feature = "codegen")]
+#[macro_export]
+#[doc(hidden)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! _butler_codegen_entry {
+    ($static_ident:ident, $entry:expr) => {
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        pub(crate) static $static_ident: ::bevy_butler::__internal::ButlerPluginRegistryEntryFactory =
+            $entry;
+
+        const _: () = ::bevy_butler::__internal::check_codegen_entry(
+            crate::__BUTLER_CODEGEN_ENTRIES,
+            ::core::module_path!(),
+            ::core::stringify!($static_ident),
+        );
+    };
+}
+
+// `include_butler_entries!` already reports the missing feature
+#[cfg(not(feature = "codegen"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_codegen_entry {
+    ($static_ident:ident, $entry:expr) => {};
+}
+
+/// Submits the table generated for the calling crate, which is included at its root by
+/// [`include_butler_entries!`](crate::include_butler_entries).
+#[cfg(feature = "codegen")]
+#[macro_export]
+#[doc(hidden)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! _butler_submit_codegen_entries {
+    () => {
+        ::bevy_butler::__internal::submit_codegen_entries(crate::__butler_codegen_entries);
+    };
+}
+
+#[cfg(not(feature = "codegen"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_submit_codegen_entries {
+    () => {};
+}
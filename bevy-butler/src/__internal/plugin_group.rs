@@ -1,22 +1,53 @@
-use std::{
-    any::{type_name, TypeId},
-    collections::HashMap,
-    sync::LazyLock,
-};
+use alloc::vec::Vec;
+use bevy_platform::sync::LazyLock;
+use bevy_platform::collections::HashMap;
+use core::any::{type_name, TypeId};
 
 use bevy_app::PluginGroupBuilder;
 
-type PluginGroupStep = fn(PluginGroupBuilder) -> PluginGroupBuilder;
+use crate::{logging, ButlerFilter};
 
+pub(crate) type PluginGroupStep = fn(PluginGroupBuilder) -> PluginGroupBuilder;
+
+#[derive(Clone, Copy)]
 pub struct ButlerPluginGroupRegistryEntryFactory {
     pub type_factory: fn() -> TypeId,
+    /// The identifier that [`ButlerFilter`] patterns are matched against.
+    pub id: &'static str,
     pub group_factory: PluginGroupStep,
 }
 
-pub struct ButlerPluginGroupRegistry(HashMap<TypeId, Vec<PluginGroupStep>>);
+pub struct ButlerPluginGroupRegistry(HashMap<TypeId, Vec<ButlerPluginGroupRegistryEntryFactory>>);
 
 impl ButlerPluginGroupRegistry {
-    pub(crate) fn get_factories(&'static self, marker: TypeId) -> &'static [PluginGroupStep] {
+    /// Groups `factories` by the plugin group that they are registered to.
+    pub(crate) fn from_factories<'a>(
+        factories: impl IntoIterator<Item = &'a ButlerPluginGroupRegistryEntryFactory>,
+    ) -> Self {
+        let mut count = 0;
+        let mut registry: HashMap<TypeId, Vec<ButlerPluginGroupRegistryEntryFactory>> =
+            HashMap::new();
+        factories.into_iter().for_each(|factory| {
+            registry
+                .entry((factory.type_factory)())
+                .or_default()
+                .push(*factory);
+            count += 1;
+        });
+
+        // Trim down
+        registry.values_mut().for_each(|vec| vec.shrink_to_fit());
+        registry.shrink_to_fit();
+
+        logging::debug!("Building ButlerPluginGroupRegistry from {count} entries");
+
+        ButlerPluginGroupRegistry(registry)
+    }
+
+    pub(crate) fn get_factories(
+        &'static self,
+        marker: TypeId,
+    ) -> &'static [ButlerPluginGroupRegistryEntryFactory] {
         self.0
             .get(&marker)
             .map(|v| v.as_slice())
@@ -33,7 +64,7 @@ pub static BUTLER_PLUGIN_GROUP_SLICE: [ButlerPluginGroupRegistryEntryFactory] =
 
 pub static BUTLER_PLUGIN_GROUP_REGISTRY: LazyLock<ButlerPluginGroupRegistry> =
     LazyLock::new(|| {
-        #[cfg(target_arch = "wasm32")]
+        #[cfg(all(target_arch = "wasm32", feature = "wasm-experimental"))]
         crate::_initialize();
 
         #[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
@@ -41,32 +72,55 @@ pub static BUTLER_PLUGIN_GROUP_REGISTRY: LazyLock<ButlerPluginGroupRegistry> =
         #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
         let iter = ::inventory::iter::<ButlerPluginGroupRegistryEntryFactory>.into_iter();
 
-        let mut count = 0;
-        let mut registry: HashMap<TypeId, Vec<PluginGroupStep>> = HashMap::new();
-        iter.for_each(|factory| {
-            registry
-                .entry((factory.type_factory)())
-                .or_default()
-                .push(factory.group_factory);
-            count += 1;
-        });
-
-        // Trim down
-        registry.values_mut().for_each(|vec| vec.shrink_to_fit());
-        registry.shrink_to_fit();
+        ButlerPluginGroupRegistry::from_factories(iter)
+    });
 
-        bevy_log::debug!("Building ButlerPluginGroupRegistry from {count} entries");
+/// The registry of every entry added to a butler plugin group.
+pub(crate) fn butler_plugin_group_registry() -> &'static ButlerPluginGroupRegistry {
+    &BUTLER_PLUGIN_GROUP_REGISTRY
+}
 
-        ButlerPluginGroupRegistry(registry)
-    });
+/// Implemented by `#[butler_plugin_group]`, and required of every `to_group = ...` target.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a #[butler_plugin_group]",
+    label = "not a #[butler_plugin_group]",
+    note = "add #[butler_plugin_group] to `{Self}`"
+)]
+pub trait ButlerPluginGroupMarker: 'static {
+    /// The marker that entries of this plugin group are registered under.
+    fn butler_plugin_group_marker() -> TypeId;
+}
 
-pub trait ButlerPluginGroup {
-    fn register_plugins(mut builder: PluginGroupBuilder, marker: TypeId) -> PluginGroupBuilder {
-        let factories = BUTLER_PLUGIN_GROUP_REGISTRY.get_factories(marker);
-        for plugin_factory in factories {
-            builder = plugin_factory(builder);
+pub trait ButlerPluginGroup: ButlerPluginGroupMarker {
+    /// Submits the entries of the group's crate to the registry, when its entries
+    /// are registered through `bevy-butler-build`.
+    fn submit_codegen_entries() {}
+
+    /// Runs the entries of the group on `builder`, skipping those that `filter` doesn't allow.
+    fn register_plugins(
+        mut builder: PluginGroupBuilder,
+        marker: TypeId,
+        filter: Option<&ButlerFilter>,
+    ) -> PluginGroupBuilder {
+        Self::submit_codegen_entries();
+        let factories = butler_plugin_group_registry().get_factories(marker).iter().copied();
+        #[cfg(feature = "codegen")]
+        let factories = factories.chain(super::codegen::butler_plugin_group_factories(marker));
+
+        let mut ran = 0;
+        for factory in factories {
+            if filter.is_some_and(|filter| !filter.is_allowed(factory.id)) {
+                logging::debug!(
+                    "{} skipped {} due to ButlerFilter",
+                    type_name::<Self>(),
+                    factory.id
+                );
+                continue;
+            }
+            builder = (factory.group_factory)(builder);
+            ran += 1;
         }
-        bevy_log::debug!("{} ran {} factories", type_name::<Self>(), factories.len());
+        logging::debug!("{} ran {} factories", type_name::<Self>(), ran);
         builder
     }
 }
@@ -86,7 +140,10 @@ macro_rules! _butler_plugin_group_entry {
     };
 }
 
-#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+#[cfg(any(
+    all(target_arch = "wasm32", feature = "wasm-experimental"),
+    all(not(target_arch = "wasm32"), feature = "inventory")
+))]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! _butler_plugin_group_entry {
@@ -94,3 +151,41 @@ macro_rules! _butler_plugin_group_entry {
         ::bevy_butler::__internal::inventory::submit!($entry);
     };
 }
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm-experimental")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_plugin_group_entry {
+    ($static_ident:ident, $entry:expr) => {
+        ::core::compile_error!(
+            "WebAssembly support in bevy-butler is experimental. Register the entries of this crate \
+            through `bevy-butler-build` instead, or enable the `wasm-experimental` feature"
+        );
+    };
+}
+
+#[cfg(feature = "codegen")]
+#[macro_export]
+#[doc(hidden)]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! _butler_codegen_group_entry {
+    ($static_ident:ident, $entry:expr) => {
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        pub(crate) static $static_ident: ::bevy_butler::__internal::ButlerPluginGroupRegistryEntryFactory =
+            $entry;
+
+        const _: () = ::bevy_butler::__internal::check_codegen_entry(
+            crate::__BUTLER_CODEGEN_ENTRIES,
+            ::core::module_path!(),
+            ::core::stringify!($static_ident),
+        );
+    };
+}
+
+#[cfg(not(feature = "codegen"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_codegen_group_entry {
+    ($static_ident:ident, $entry:expr) => {};
+}
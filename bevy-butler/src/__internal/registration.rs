@@ -0,0 +1,32 @@
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_platform::collections::{hash_map::Entry, HashMap};
+use core::any::{type_name, TypeId};
+
+use crate::logging;
+
+/// The butler plugin that first registered each resource, state and event type.
+#[derive(Resource, Default)]
+pub struct ButlerRegistrations(HashMap<(&'static str, TypeId), &'static str>);
+
+/// Warns if `T` was already registered as a `kind` by a butler plugin other than `P`.
+pub fn check_duplicate_registration<P: 'static, T: 'static>(app: &mut App, kind: &'static str) {
+    let plugin = type_name::<P>();
+    let mut registrations = app
+        .world_mut()
+        .get_resource_or_init::<ButlerRegistrations>();
+
+    match registrations.0.entry((kind, TypeId::of::<T>())) {
+        Entry::Occupied(entry) if *entry.get() != plugin => {
+            logging::warn!(
+                "{kind} {} is registered by both {} and {plugin}. Only one of them should register it.",
+                type_name::<T>(),
+                entry.get()
+            );
+        }
+        Entry::Occupied(_) => {}
+        Entry::Vacant(entry) => {
+            entry.insert(plugin);
+        }
+    }
+}
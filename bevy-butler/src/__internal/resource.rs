@@ -0,0 +1,523 @@
+use bevy_app::{App, Last};
+use bevy_ecs::{prelude::*, reflect::ReflectResource};
+use bevy_reflect::{PartialReflect, ReflectRef};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{any::type_name, fmt::Display};
+#[cfg(feature = "std")]
+use bevy_reflect::Struct;
+#[cfg(feature = "serde")]
+use {
+    bevy_app::AppExit,
+    bevy_platform::time::Instant,
+    core::time::Duration,
+};
+
+use crate::logging;
+
+/// The return value of a resource initializer, either the resource itself or a `Result` of it.
+pub trait ResourceInit<R> {
+    fn into_result(self) -> Result<R, String>;
+}
+
+impl<R> ResourceInit<R> for R {
+    fn into_result(self) -> Result<R, String> {
+        Ok(self)
+    }
+}
+
+impl<R, E: Display> ResourceInit<R> for Result<R, E> {
+    fn into_result(self) -> Result<R, String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// Runs the initializer `init` for the resource `R` of the plugin `P`.
+///
+/// If the initializer fails, this panics, or logs the error and returns `None` if `skip_on_error` is set.
+pub fn run_resource_init<P, R, V: ResourceInit<R>>(
+    world: &mut World,
+    init: impl FnOnce(&mut World) -> V,
+    skip_on_error: bool,
+) -> Option<R> {
+    match init(world).into_result() {
+        Ok(resource) => Some(resource),
+        Err(e) if skip_on_error => {
+            logging::error!(
+                "Failed to initialize {} for {}, skipping: {e}",
+                type_name::<R>(),
+                type_name::<P>()
+            );
+            None
+        }
+        Err(e) => panic!(
+            "Failed to initialize {} for {}: {e}",
+            type_name::<R>(),
+            type_name::<P>()
+        ),
+    }
+}
+
+/// Loads a resource from the file at `path`.
+///
+/// Returns `None` if the file doesn't exist, so the caller can fall back to the resource's
+/// initial value. For `from_file`, this is logged as a warning, and any other error panics.
+/// A `persist`ed file may have been written by an older build or cut off while saving, so with
+/// `persist`, other errors are logged and fall back to the initial value too, which replaces the
+/// file on the next save.
+#[cfg(feature = "serde")]
+pub fn load_resource_file<R: serde::de::DeserializeOwned>(path: &str, persist: bool) -> Option<R> {
+    match crate::file::load_file::<R>(path) {
+        Ok(resource) => Some(resource),
+        Err(e) if e.is_not_found() => {
+            if !persist {
+                logging::warn!(
+                    "{path} does not exist, falling back to the initial value of {}",
+                    type_name::<R>()
+                );
+            }
+            None
+        }
+        Err(e) if persist => {
+            logging::error!(
+                "Failed to load {} from {e}, falling back to its initial value",
+                type_name::<R>()
+            );
+            None
+        }
+        Err(e) => panic!("Failed to load {} from {e}", type_name::<R>()),
+    }
+}
+
+/// The schedule that saves `persist`ed resources, which runs after [`Last`].
+///
+/// Running after every other schedule of the frame means an [`AppExit`] written anywhere in
+/// [`Last`] is still seen before the app exits.
+#[cfg(feature = "serde")]
+#[derive(bevy_ecs::schedule::ScheduleLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SavePersistedResources;
+
+/// Saves the resource `R` to `path` when the app exits.
+///
+/// If `debounce` is set, the resource is also saved once it has stopped changing for that long.
+/// Errors are logged rather than panicking, so a failed save never takes down the app.
+#[cfg(feature = "serde")]
+pub fn add_resource_persistence<R: Resource + serde::Serialize>(
+    app: &mut App,
+    path: &'static str,
+    debounce: Option<Duration>,
+) {
+    if app.get_schedule(SavePersistedResources).is_none() {
+        app.init_schedule(SavePersistedResources);
+        app.world_mut()
+            .resource_mut::<bevy_app::MainScheduleOrder>()
+            .insert_after(Last, SavePersistedResources);
+    }
+
+    // The resource is missing if its initializer failed with `on_init_error = skip`
+    app.add_systems(
+        SavePersistedResources,
+        (move |res: Option<Res<R>>| {
+            if let Some(res) = res {
+                save_resource(&*res, path);
+            }
+        })
+        .run_if(on_event::<AppExit>),
+    );
+
+    let Some(debounce) = debounce else {
+        return;
+    };
+
+    app.add_systems(
+        Last,
+        move |res: Option<Res<R>>, mut changed_at: Local<Option<Instant>>| {
+            let Some(res) = res else {
+                *changed_at = None;
+                return;
+            };
+
+            // Adding the resource counts as a change, but there's nothing new to save
+            if res.is_changed() && !res.is_added() {
+                *changed_at = Some(Instant::now());
+            }
+
+            if changed_at.is_some_and(|t| t.elapsed() >= debounce) {
+                *changed_at = None;
+                save_resource(&*res, path);
+            }
+        },
+    );
+}
+
+#[cfg(feature = "serde")]
+fn save_resource<R: serde::Serialize>(resource: &R, path: &str) {
+    if let Some(dir) = std::path::Path::new(path).parent() {
+        std::fs::create_dir_all(dir).ok();
+    }
+
+    match crate::file::save_file(resource, path) {
+        Ok(()) => logging::debug!("Saved {} to {path}", type_name::<R>()),
+        Err(e) => logging::error!("Failed to save {} to {e}", type_name::<R>()),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_persist_resource {
+    ($app:expr, $res:ty, $path:expr, $debounce:expr) => {
+        ::bevy_butler::__internal::add_resource_persistence::<$res>($app, $path, $debounce)
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_persist_resource {
+    ($app:expr, $res:ty, $path:expr, $debounce:expr) => {
+        ::core::compile_error!(
+            "`persist` requires the `ron`, `json` or `toml` feature of bevy-butler"
+        )
+    };
+}
+
+#[cfg(feature = "serde")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_load_resource {
+    ($res:ty, $path:expr, $persist:expr) => {
+        ::bevy_butler::__internal::load_resource_file::<$res>($path, $persist)
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_load_resource {
+    ($res:ty, $path:expr, $persist:expr) => {
+        ::core::compile_error!(
+            "`from_file` and `persist` require the `ron`, `json` or `toml` feature of bevy-butler"
+        )
+    };
+}
+
+/// Applies overrides for the fields of a resource from environment variables and command-line
+/// arguments.
+///
+/// A field `gravity` with the prefix `GAME_PHYSICS` can be set with either the environment
+/// variable `GAME_PHYSICS_GRAVITY=12` or the argument `--game-physics-gravity=12`.
+/// Arguments take priority over environment variables.
+///
+/// Overrides are applied through [`Reflect`](bevy_reflect::Reflect) if the resource is a
+/// reflected struct, and otherwise through `serde` with the `json` feature.
+/// Overrides that don't match any field panic, unless `ignore_unknown` is set, and so do values
+/// that can't be parsed. Does nothing if the resource is missing, like when its initializer failed
+/// with `on_init_error = skip`.
+#[cfg(feature = "std")]
+pub struct ResourceOverrides<R>(pub core::marker::PhantomData<R>);
+
+/// An environment variable or argument that overrides the field `field` of a resource.
+#[cfg(feature = "std")]
+struct Override {
+    key: String,
+    field: String,
+    value: String,
+}
+
+/// Collects the overrides for `prefix`, with arguments after environment variables.
+///
+/// Variables and arguments that aren't valid UTF-8 can't be overrides, so they're skipped.
+#[cfg(feature = "std")]
+fn collect_overrides(prefix: &str) -> Vec<Override> {
+    let key_prefix = format!("{}_", prefix.to_uppercase());
+    let vars = std::env::vars_os()
+        .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)));
+    let args = std::env::args_os().filter_map(|arg| {
+        let arg = arg.into_string().ok()?;
+        let (key, value) = arg.strip_prefix("--")?.split_once('=')?;
+        Some((key.to_uppercase().replace('-', "_"), value.to_string()))
+    });
+    vars.chain(args)
+        .filter_map(|(key, value)| {
+            let field = key.strip_prefix(&key_prefix)?.to_lowercase();
+            Some(Override { key, field, value })
+        })
+        .collect()
+}
+
+/// Panics for an override that doesn't match any field, unless `ignore_unknown` is set.
+#[cfg(feature = "std")]
+fn unknown_override<R>(key: &str, fields: &[&str], ignore_unknown: bool) {
+    if ignore_unknown {
+        logging::debug!(
+            "Skipping {key}, since it does not match any field of {}",
+            type_name::<R>()
+        );
+        return;
+    }
+
+    panic!(
+        "{key} does not match any field of {}. Expected one of: {}",
+        type_name::<R>(),
+        fields.join(", ")
+    );
+}
+
+/// Applies overrides to a reflected struct resource.
+#[cfg(feature = "std")]
+pub trait ApplyReflectOverrides {
+    fn apply(&self, world: &mut World, prefix: &str, ignore_unknown: bool);
+}
+
+#[cfg(feature = "std")]
+impl<R: Resource + Struct> ApplyReflectOverrides for ResourceOverrides<R> {
+    fn apply(&self, world: &mut World, prefix: &str, ignore_unknown: bool) {
+        let overrides = collect_overrides(prefix);
+        if overrides.is_empty() {
+            return;
+        }
+
+        let Some(mut resource) = world.get_resource_mut::<R>() else {
+            logging::debug!(
+                "Skipping overrides for {}, since it wasn't inserted",
+                type_name::<R>()
+            );
+            return;
+        };
+        for Override { key, field, value } in overrides {
+            let Some(target) = resource.field_mut(&field) else {
+                let fields: Vec<_> = (0..resource.field_len())
+                    .filter_map(|i| resource.name_at(i))
+                    .collect();
+                unknown_override::<R>(&key, &fields, ignore_unknown);
+                continue;
+            };
+
+            if let Err(e) = set_field_from_str(target, &value) {
+                panic!(
+                    "Failed to apply {key}={value} to {}::{field}: {e}",
+                    type_name::<R>()
+                );
+            }
+
+            logging::info!("Overriding {}::{field} with {key}={value}", type_name::<R>());
+        }
+    }
+}
+
+/// Applies overrides to a resource through `serde`. Only used if the resource doesn't implement
+/// [`ApplyReflectOverrides`].
+#[cfg(feature = "json")]
+pub trait ApplySerdeOverrides {
+    fn apply(&self, world: &mut World, prefix: &str, ignore_unknown: bool);
+}
+
+#[cfg(feature = "json")]
+impl<R> ApplySerdeOverrides for &ResourceOverrides<R>
+where
+    R: Resource + serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn apply(&self, world: &mut World, prefix: &str, ignore_unknown: bool) {
+        use serde_json::Value;
+
+        let overrides = collect_overrides(prefix);
+        if overrides.is_empty() {
+            return;
+        }
+
+        let Some(mut resource) = world.get_resource_mut::<R>() else {
+            logging::debug!(
+                "Skipping overrides for {}, since it wasn't inserted",
+                type_name::<R>()
+            );
+            return;
+        };
+        let mut fields = match serde_json::to_value(&*resource) {
+            Ok(Value::Object(fields)) => fields,
+            Ok(_) => panic!("{} cannot be overridden, since it isn't a struct", type_name::<R>()),
+            Err(e) => panic!("Failed to serialize {} for overrides: {e}", type_name::<R>()),
+        };
+        for Override { key, field, value } in overrides {
+            let Some(target) = fields.get_mut(&field) else {
+                let names: Vec<_> = fields.keys().map(String::as_str).collect();
+                unknown_override::<R>(&key, &names, ignore_unknown);
+                continue;
+            };
+
+            // Values that aren't valid JSON, like `hello`, are taken as strings
+            *target = serde_json::from_str(&value).unwrap_or_else(|_| Value::String(value.clone()));
+            if let Err(e) = R::deserialize(Value::Object(fields.clone())) {
+                panic!(
+                    "Failed to apply {key}={value} to {}::{field}: {e}",
+                    type_name::<R>()
+                );
+            }
+
+            logging::info!("Overriding {}::{field} with {key}={value}", type_name::<R>());
+        }
+
+        match R::deserialize(Value::Object(fields)) {
+            Ok(value) => *resource = value,
+            Err(e) => panic!("Failed to apply overrides to {}: {e}", type_name::<R>()),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "json")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_apply_resource_overrides {
+    ($world:expr, $res:ty, $prefix:expr, $ignore_unknown:expr) => {{
+        use ::bevy_butler::__internal::ApplyReflectOverrides as _;
+        let overrides =
+            ::bevy_butler::__internal::ResourceOverrides::<$res>(::core::marker::PhantomData);
+        (&overrides).apply($world, $prefix, $ignore_unknown)
+    }};
+}
+
+#[cfg(feature = "json")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_apply_resource_overrides {
+    ($world:expr, $res:ty, $prefix:expr, $ignore_unknown:expr) => {{
+        use ::bevy_butler::__internal::{ApplyReflectOverrides as _, ApplySerdeOverrides as _};
+        let overrides =
+            ::bevy_butler::__internal::ResourceOverrides::<$res>(::core::marker::PhantomData);
+        (&overrides).apply($world, $prefix, $ignore_unknown)
+    }};
+}
+
+#[cfg(not(feature = "std"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_apply_resource_overrides {
+    ($world:expr, $res:ty, $prefix:expr, $ignore_unknown:expr) => {
+        ::core::compile_error!("`env_prefix` requires the `std` feature of bevy-butler")
+    };
+}
+
+#[cfg(feature = "std")]
+fn set_field_from_str(field: &mut dyn PartialReflect, value: &str) -> Result<(), String> {
+    macro_rules! parse_into {
+        ($($ty:ty),*) => {$(
+            if let Some(field) = field.try_downcast_mut::<$ty>() {
+                *field = value.parse::<$ty>().map_err(|e| e.to_string())?;
+                return Ok(());
+            }
+        )*};
+    }
+
+    parse_into!(
+        bool, char, String, f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128,
+        isize
+    );
+
+    Err(format!(
+        "fields of type {} cannot be overridden",
+        field.reflect_type_path()
+    ))
+}
+
+/// Logs whenever the resource `R` is changed.
+///
+/// If `R` is registered with [`ReflectResource`], the log includes each field that changed
+/// along with its old and new values.
+pub fn add_resource_change_tracing<R: Resource>(app: &mut App) {
+    app.add_systems(Last, trace_resource_changes::<R>);
+}
+
+fn trace_resource_changes<R: Resource>(
+    world: &mut World,
+    mut snapshot: Local<Option<Box<dyn PartialReflect>>>,
+) {
+    let Some(resource) = world.get_resource_ref::<R>() else {
+        *snapshot = None;
+        return;
+    };
+    let (changed, added) = (resource.is_changed(), resource.is_added());
+    let tick = resource.last_changed().get();
+    let location = resource
+        .changed_by()
+        .into_option()
+        .map(|location| format!(" by {location}"))
+        .unwrap_or_default();
+
+    if !changed && snapshot.is_some() {
+        return;
+    }
+
+    let current = world.get_resource::<AppTypeRegistry>().and_then(|registry| {
+        let registry = registry.read();
+        let reflect = registry.get_type_data::<ReflectResource>(core::any::TypeId::of::<R>())?;
+        Some(reflect.reflect(&*world).ok()?.to_dynamic())
+    });
+    let previous = core::mem::replace(&mut *snapshot, current);
+
+    if !changed || added {
+        return;
+    }
+
+    let diff = match (&previous, &*snapshot) {
+        (Some(previous), Some(current)) => diff_reflect(previous.as_ref(), current.as_ref()),
+        _ => Vec::new(),
+    };
+
+    match diff.is_empty() {
+        true => logging::info!("{} changed at tick {tick}{location}", type_name::<R>()),
+        false => logging::info!(
+            "{} changed at tick {tick}{location}:\n  {}",
+            type_name::<R>(),
+            diff.join("\n  ")
+        ),
+    }
+}
+
+/// Lists each field that differs between `previous` and `current` as `path: old -> new`,
+/// descending into nested structs and tuple structs.
+fn diff_reflect(previous: &dyn PartialReflect, current: &dyn PartialReflect) -> Vec<String> {
+    let mut diff = Vec::new();
+    diff_fields(String::new(), previous, current, &mut diff);
+    diff
+}
+
+fn diff_fields(
+    path: String,
+    previous: &dyn PartialReflect,
+    current: &dyn PartialReflect,
+    diff: &mut Vec<String>,
+) {
+    let fields: Vec<(String, &dyn PartialReflect, &dyn PartialReflect)> =
+        match (previous.reflect_ref(), current.reflect_ref()) {
+            (ReflectRef::Struct(previous), ReflectRef::Struct(current)) => (0..current.field_len())
+                .filter_map(|i| {
+                    let name = current.name_at(i)?;
+                    Some((name.to_string(), previous.field(name)?, current.field_at(i)?))
+                })
+                .collect(),
+            (ReflectRef::TupleStruct(previous), ReflectRef::TupleStruct(current)) => (0..current
+                .field_len())
+                .filter_map(|i| Some((i.to_string(), previous.field(i)?, current.field(i)?)))
+                .collect(),
+            _ => {
+                let (previous, current) = (format!("{previous:?}"), format!("{current:?}"));
+                if previous != current {
+                    let path = if path.is_empty() { "value" } else { &path };
+                    diff.push(format!("{path}: {previous} -> {current}"));
+                }
+                return;
+            }
+        };
+
+    for (name, previous, current) in fields {
+        let path = match path.is_empty() {
+            true => name,
+            false => format!("{path}.{name}"),
+        };
+        diff_fields(path, previous, current, diff);
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::{vec, vec::Vec};
+use bevy_ecs::{name::Name, prelude::*};
+
+use crate::ButlerSpawned;
+
+/// Marker for [`SpawnBundles`] implemented on a single [`Bundle`].
+pub struct SingleBundle;
+
+/// Marker for [`SpawnBundles`] implemented on a [`Vec`] of bundles.
+pub struct BundleVec;
+
+/// The return value of a `#[spawn]` function, either a single [`Bundle`] or a [`Vec`] of them.
+pub trait SpawnBundles<M>: Send + 'static {
+    type Bundle: Bundle;
+
+    fn into_bundles(self) -> Vec<Self::Bundle>;
+}
+
+impl<B: Bundle> SpawnBundles<SingleBundle> for B {
+    type Bundle = B;
+
+    fn into_bundles(self) -> Vec<B> {
+        vec![self]
+    }
+}
+
+impl<B: Bundle> SpawnBundles<BundleVec> for Vec<B> {
+    type Bundle = B;
+
+    fn into_bundles(self) -> Vec<B> {
+        self
+    }
+}
+
+/// Creates a system that spawns the bundles piped into it from a `#[spawn]` function.
+///
+/// Each entity is also given `name` if set, and a [`ButlerSpawned<P>`] marker if `marker` is set.
+pub fn spawn_bundles<P: 'static, M, B: SpawnBundles<M>>(
+    name: Option<&'static str>,
+    marker: bool,
+) -> impl FnMut(In<B>, Commands) + Send + Sync + 'static {
+    move |In(bundles), mut commands| {
+        for bundle in bundles.into_bundles() {
+            let mut entity = commands.spawn(bundle);
+            if let Some(name) = name {
+                entity.insert(Name::new(name));
+            }
+            if marker {
+                entity.insert(ButlerSpawned::<P>::default());
+            }
+        }
+    }
+}
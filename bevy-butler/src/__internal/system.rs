@@ -0,0 +1,106 @@
+use bevy_app::App;
+use bevy_ecs::{
+    prelude::*,
+    system::{Adapt, IntoAdapterSystem, SystemIn, SystemInput},
+};
+use bevy_time::{Time, Timer, TimerMode};
+use alloc::sync::Arc;
+use bevy_platform::{sync::Mutex, time::Instant};
+use core::{any::type_name, marker::PhantomData, time::Duration};
+
+use crate::{timings::SystemTiming, ButlerSystemTimings};
+
+/// A run condition that is true once every `duration`, measured with [`Time`].
+///
+/// Inside `FixedUpdate`, this uses the fixed timestep.
+pub fn every(duration: Duration) -> impl FnMut(Res<Time>) -> bool + Clone + Send + Sync + 'static {
+    let mut timer = Timer::new(duration, TimerMode::Repeating);
+    move |time: Res<Time>| {
+        timer.tick(time.delta());
+        timer.just_finished()
+    }
+}
+
+/// A run condition that is true once every `frames` times it is checked.
+pub fn every_frames(frames: u32) -> impl FnMut() -> bool + Clone + Send + Sync + 'static {
+    assert!(frames > 0, "`every_frames` must be greater than 0");
+    let mut frame = 0;
+    move || {
+        frame = (frame + 1) % frames;
+        frame == 0
+    }
+}
+
+/// Adapts a system to run inside a tracing span, and to record its [`SystemTiming`].
+///
+/// Spans are only entered with the `std` feature, since `bevy_log` requires it.
+pub struct InstrumentSystem<P> {
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    name: &'static str,
+    timing: Option<Arc<Mutex<SystemTiming>>>,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: 'static, S: System> Adapt<S> for InstrumentSystem<P> {
+    type In = S::In;
+    type Out = S::Out;
+
+    fn adapt(
+        &mut self,
+        input: <Self::In as SystemInput>::Inner<'_>,
+        run_system: impl FnOnce(SystemIn<'_, S>) -> S::Out,
+    ) -> Self::Out {
+        #[cfg(feature = "std")]
+        let _span = bevy_log::info_span!(
+            "butler_system",
+            plugin = type_name::<P>(),
+            system = self.name
+        )
+        .entered();
+
+        let Some(timing) = &self.timing else {
+            return run_system(input);
+        };
+
+        let start = Instant::now();
+        let out = run_system(input);
+        let elapsed = start.elapsed();
+
+        let mut timing = timing.lock().unwrap();
+        timing.runs += 1;
+        timing.total += elapsed;
+        timing.last = elapsed;
+        out
+    }
+}
+
+/// Wraps `system` in a tracing span named after it and the plugin `P`.
+///
+/// If [`ButlerSystemTimings`] exists, the system's runs are also recorded there.
+pub fn instrument_system<P: 'static, I: SystemInput, O, M, S: IntoSystem<I, O, M>>(
+    app: &mut App,
+    name: &'static str,
+    system: S,
+) -> IntoAdapterSystem<InstrumentSystem<P>, S> {
+    let timing = app
+        .world_mut()
+        .get_resource_mut::<ButlerSystemTimings>()
+        .map(|mut timings| timings.track(name, type_name::<P>()));
+
+    IntoAdapterSystem::new(
+        InstrumentSystem {
+            name,
+            timing,
+            _marker: PhantomData,
+        },
+        system,
+    )
+}
+
+/// Stands in for the macro left behind by an earlier, identical `butler_chain!` invocation,
+/// when there is none in scope.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _butler_chain_unique {
+    ($($error:tt)*) => {};
+}
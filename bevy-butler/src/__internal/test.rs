@@ -0,0 +1,50 @@
+use bevy_app::App;
+use core::time::Duration;
+
+/// The timeout of a `#[butler_test]` that doesn't set one.
+pub const DEFAULT_TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Runs `test` on its own thread, and panics if it doesn't finish within `timeout`.
+///
+/// Panics from `test` are forwarded, so `#[should_panic]` works as usual.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub fn run_butler_test(timeout: Duration, test: impl FnOnce() + Send + 'static) {
+    use std::sync::mpsc::{self, RecvTimeoutError};
+
+    let (sender, receiver) = mpsc::channel();
+    let handle = std::thread::spawn(move || {
+        test();
+        sender.send(()).ok();
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(()) | Err(RecvTimeoutError::Disconnected) => {
+            if let Err(panic) = handle.join() {
+                std::panic::resume_unwind(panic);
+            }
+        }
+        Err(RecvTimeoutError::Timeout) => panic!("Test timed out after {timeout:?}"),
+    }
+}
+
+/// Threads aren't available on wasm or without `std`, so tests run without a timeout.
+#[cfg(any(not(feature = "std"), target_arch = "wasm32"))]
+pub fn run_butler_test(_timeout: Duration, test: impl FnOnce() + Send + 'static) {
+    test();
+}
+
+/// Finishes building `app`, then updates it `updates` times, or until [`AppExit`](bevy_app::AppExit)
+/// is sent if `updates` is `None`.
+pub fn update_butler_test(app: &mut App, updates: Option<usize>) {
+    app.finish();
+    app.cleanup();
+
+    let mut remaining = updates;
+    while remaining != Some(0) {
+        app.update();
+        if app.should_exit().is_some() {
+            break;
+        }
+        remaining = remaining.map(|n| n - 1);
+    }
+}
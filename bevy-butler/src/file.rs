@@ -0,0 +1,156 @@
+use alloc::string::{String, ToString};
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A file format supported by butler's file-backed features, chosen by file extension.
+///
+/// Each format is behind a cargo feature of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    /// `.ron` files
+    #[cfg(feature = "ron")]
+    Ron,
+    /// `.json` files
+    #[cfg(feature = "json")]
+    Json,
+    /// `.toml` files
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl FileFormat {
+    /// Returns the format matching the extension of `path`, if it is supported and its feature is enabled.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            #[cfg(feature = "ron")]
+            "ron" => Some(Self::Ron),
+            #[cfg(feature = "json")]
+            "json" => Some(Self::Json),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// Serializes `value` into a string of this format.
+    #[cfg_attr(
+        not(any(feature = "ron", feature = "json", feature = "toml")),
+        allow(unused_variables)
+    )]
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String, String> {
+        match *self {
+            #[cfg(feature = "ron")]
+            Self::Ron => {
+                ron::ser::to_string_pretty(value, Default::default()).map_err(|e| e.to_string())
+            }
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::to_string_pretty(value).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Deserializes a value of this format from `input`.
+    #[cfg_attr(
+        not(any(feature = "ron", feature = "json", feature = "toml")),
+        allow(unused_variables)
+    )]
+    pub fn deserialize<T: DeserializeOwned>(&self, input: &str) -> Result<T, String> {
+        match *self {
+            #[cfg(feature = "ron")]
+            Self::Ron => ron::from_str(input).map_err(|e| e.to_string()),
+            #[cfg(feature = "json")]
+            Self::Json => serde_json::from_str(input).map_err(|e| e.to_string()),
+            #[cfg(feature = "toml")]
+            Self::Toml => toml::from_str(input).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// What went wrong while reading or writing a file.
+#[derive(Debug)]
+pub enum FileErrorKind {
+    /// The file could not be read or written
+    Io(std::io::Error),
+    /// The file extension does not match any enabled [`FileFormat`]
+    UnsupportedFormat,
+    /// The value could not be serialized
+    Serialize(String),
+    /// The file contents could not be deserialized
+    Deserialize(String),
+}
+
+/// An error produced while reading or writing a file, along with the path of the file.
+#[derive(Debug)]
+pub struct FileError {
+    pub path: PathBuf,
+    pub kind: FileErrorKind,
+}
+
+impl FileError {
+    /// Returns `true` if the error was caused by the file not existing.
+    pub fn is_not_found(&self) -> bool {
+        matches!(&self.kind, FileErrorKind::Io(e) if e.kind() == std::io::ErrorKind::NotFound)
+    }
+}
+
+impl Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = self.path.display();
+        match &self.kind {
+            FileErrorKind::Io(e) => write!(f, "{path}: {e}"),
+            FileErrorKind::UnsupportedFormat => write!(
+                f,
+                "{path}: unsupported file format (is the matching bevy-butler feature enabled?)"
+            ),
+            FileErrorKind::Serialize(e) => write!(f, "{path}: failed to serialize: {e}"),
+            FileErrorKind::Deserialize(e) => write!(f, "{path}: failed to deserialize: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            FileErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Reads and deserializes a `T` from the file at `path`, using the format matching its extension.
+pub fn load_file<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T, FileError> {
+    let path = path.as_ref();
+    let error = |kind| FileError {
+        path: path.to_path_buf(),
+        kind,
+    };
+
+    let format =
+        FileFormat::from_path(path).ok_or_else(|| error(FileErrorKind::UnsupportedFormat))?;
+    let contents = fs::read_to_string(path).map_err(|e| error(FileErrorKind::Io(e)))?;
+    format
+        .deserialize(&contents)
+        .map_err(|e| error(FileErrorKind::Deserialize(e)))
+}
+
+/// Serializes `value` and writes it to the file at `path`, using the format matching its extension.
+pub fn save_file<T: Serialize>(value: &T, path: impl AsRef<Path>) -> Result<(), FileError> {
+    let path = path.as_ref();
+    let error = |kind| FileError {
+        path: path.to_path_buf(),
+        kind,
+    };
+
+    let format =
+        FileFormat::from_path(path).ok_or_else(|| error(FileErrorKind::UnsupportedFormat))?;
+    let contents = format
+        .serialize(value)
+        .map_err(|e| error(FileErrorKind::Serialize(e)))?;
+    fs::write(path, contents).map_err(|e| error(FileErrorKind::Io(e)))
+}
@@ -0,0 +1,161 @@
+use alloc::{string::String, vec::Vec};
+use bevy_app::{PluginGroup, PluginGroupBuilder};
+use bevy_ecs::prelude::*;
+
+use crate::__internal::ButlerPluginGroup;
+
+/// Skips butler entries when their plugin is built, based on their identifiers.
+///
+/// Every entry registered by a butler macro, such as a system, observer, resource or nested
+/// plugin, is identified by the module path of the annotated item followed by its name,
+/// like `my_game::netcode::sync_positions` or `my_game::physics::Gravity`. Generic entries
+/// include their generic arguments, like `my_game::stats::track::<Health>`.
+///
+/// Patterns may use `*` to match any sequence of characters, and are matched against the
+/// identifier with any number of leading path segments removed. So `netcode::*` matches
+/// `my_game::netcode::sync_positions`, as well as `my_game::server::netcode::send`.
+///
+/// The filter must be inserted before the plugins it applies to are added. Entries that add
+/// plugins to a [`#[butler_plugin_group]`](crate::butler_plugin_group) are filtered through
+/// [`build_group`](Self::build_group) instead.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// #[butler_plugin]
+/// struct GamePlugin;
+///
+/// mod netcode {
+///     # use bevy_butler::*;
+///     # use bevy::prelude::*;
+///     #[add_system(plugin = super::GamePlugin, schedule = Update)]
+///     fn sync_positions() {}
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .insert_resource(ButlerFilter::deny(["netcode::*"]))
+///     .add_plugins(GamePlugin);
+/// # }
+/// ```
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ButlerFilter {
+    allow: Option<Vec<String>>,
+    deny: Vec<String>,
+}
+
+impl ButlerFilter {
+    /// Creates a filter that skips every entry matching one of `patterns`.
+    pub fn deny<S: Into<String>>(patterns: impl IntoIterator<Item = S>) -> Self {
+        Self::default().and_deny(patterns)
+    }
+
+    /// Creates a filter that skips every entry that doesn't match any of `patterns`.
+    pub fn allow<S: Into<String>>(patterns: impl IntoIterator<Item = S>) -> Self {
+        Self::default().and_allow(patterns)
+    }
+
+    /// Also skips every entry matching one of `patterns`.
+    pub fn and_deny<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.deny.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Also allows every entry matching one of `patterns`. Once any pattern is allowed,
+    /// entries that don't match an allowed pattern are skipped.
+    pub fn and_allow<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.allow
+            .get_or_insert_with(Vec::new)
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Builds the plugin group `G`, skipping the plugins that its entries would add if they
+    /// aren't allowed.
+    ///
+    /// Plugin groups are built without access to the [`App`](bevy_app::App), so the entries of
+    /// a group are only filtered through this method, not by inserting the filter as a resource.
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_butler::*;
+    /// #[butler_plugin_group]
+    /// struct GamePlugins;
+    ///
+    /// #[butler_plugin]
+    /// #[add_plugin(to_group = GamePlugins)]
+    /// struct DebugOverlayPlugin;
+    ///
+    /// # fn main() {
+    /// App::new().add_plugins(ButlerFilter::deny(["DebugOverlayPlugin"]).build_group(GamePlugins));
+    /// # }
+    /// ```
+    pub fn build_group<G>(&self, _group: G) -> PluginGroupBuilder
+    where
+        G: ButlerPluginGroup + PluginGroup,
+    {
+        G::register_plugins(
+            PluginGroupBuilder::start::<G>(),
+            G::butler_plugin_group_marker(),
+            Some(self),
+        )
+    }
+
+    /// Returns whether the entry with the identifier `id` should be built.
+    ///
+    /// Deny patterns take priority over allow patterns.
+    pub fn is_allowed(&self, id: &str) -> bool {
+        let matches = |patterns: &[String]| patterns.iter().any(|pattern| matches_id(pattern, id));
+
+        if matches(&self.deny) {
+            return false;
+        }
+        self.allow.as_deref().is_none_or(matches)
+    }
+}
+
+/// Matches `pattern` against `id`, or any part of `id` after a `::`.
+fn matches_id(pattern: &str, id: &str) -> bool {
+    let mut rest = id;
+    loop {
+        if matches_glob(pattern.as_bytes(), rest.as_bytes()) {
+            return true;
+        }
+        match rest.find("::") {
+            Some(i) => rest = &rest[i + 2..],
+            None => return false,
+        }
+    }
+}
+
+/// Matches `pattern` against all of `text`, where `*` matches any sequence of characters.
+///
+/// On a mismatch, only the most recent `*` is extended by one character, since extending an
+/// earlier one can't match anything the most recent one couldn't. This keeps matching
+/// proportional to `pattern.len() * text.len()` at worst, instead of exponential.
+fn matches_glob(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // The pattern position after the most recent `*`, and the text position it matches up to
+    let mut star = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                p += 1;
+                star = Some((p, t));
+            }
+            Some(&c) if c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    star = Some((star_p, t));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
@@ -0,0 +1,108 @@
+use alloc::string::String;
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugins};
+use bevy_ecs::prelude::*;
+
+use crate::{__internal::ButlerPlugin, ButlerFilter};
+
+/// Builds the entries of a single [`#[butler_plugin]`](crate::butler_plugin) into a fresh [`App`],
+/// for testing them in isolation.
+///
+/// Unlike adding the plugin, the harness only runs the plugin's butler entries, and skips any
+/// statements in a manual `impl Plugin` build function. Entries can be narrowed down further with
+/// [`only_systems`](Self::only_systems) and [`skip`](Self::skip), using the same patterns as
+/// [`ButlerFilter`].
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// #[butler_plugin]
+/// struct GamePlugin;
+///
+/// #[derive(Resource)]
+/// struct Speed(f32);
+///
+/// #[derive(Resource, Default)]
+/// struct Position(f32);
+///
+/// #[add_system(plugin = GamePlugin, schedule = Update)]
+/// fn movement(speed: Res<Speed>, mut position: ResMut<Position>) {
+///     position.0 += speed.0;
+/// }
+///
+/// #[add_system(plugin = GamePlugin, schedule = Update)]
+/// fn needs_many_resources(/* ... */) {}
+///
+/// let mut app = ButlerPluginHarness::<GamePlugin>::new()
+///     .only_systems(["movement"])
+///     .with_resource(Speed(2.0))
+///     .with_resource(Position::default())
+///     .build();
+/// app.update();
+///
+/// assert_eq!(app.world().resource::<Position>().0, 2.0);
+/// ```
+pub struct ButlerPluginHarness<P: ButlerPlugin> {
+    app: App,
+    filter: ButlerFilter,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: ButlerPlugin> Default for ButlerPluginHarness<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: ButlerPlugin> ButlerPluginHarness<P> {
+    /// Creates a harness with an empty [`App`].
+    pub fn new() -> Self {
+        Self {
+            app: App::new(),
+            filter: ButlerFilter::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Only builds the entries matching one of `patterns`, such as `movement` or `physics::*`.
+    pub fn only_systems<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.filter = self.filter.and_allow(patterns);
+        self
+    }
+
+    /// Skips the entries matching one of `patterns`.
+    pub fn skip<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.filter = self.filter.and_deny(patterns);
+        self
+    }
+
+    /// Inserts `resource` into the app before the plugin's entries are built.
+    pub fn with_resource<R: Resource>(mut self, resource: R) -> Self {
+        self.app.insert_resource(resource);
+        self
+    }
+
+    /// Adds `plugins` to the app before the plugin's entries are built.
+    pub fn with_plugins<M>(mut self, plugins: impl Plugins<M>) -> Self {
+        self.app.add_plugins(plugins);
+        self
+    }
+
+    /// Builds the selected entries of `P`, and returns the app.
+    pub fn build(mut self) -> App {
+        let previous = self.app.world_mut().remove_resource::<ButlerFilter>();
+        self.app.insert_resource(self.filter);
+        P::register_butler_systems(&mut self.app);
+
+        let world = self.app.world_mut();
+        match previous {
+            Some(previous) => world.insert_resource(previous),
+            None => drop(world.remove_resource::<ButlerFilter>()),
+        }
+
+        self.app.finish();
+        self.app.cleanup();
+        self.app
+    }
+}
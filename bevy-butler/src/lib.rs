@@ -1,8 +1,44 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Logs through `bevy_log`, or through `log` without the `std` feature, like the rest of Bevy.
+mod logging {
+    #[cfg(feature = "std")]
+    pub(crate) use bevy_log::{debug, error, info, warn};
+    #[cfg(not(feature = "std"))]
+    pub(crate) use log::{debug, error, info, warn};
+}
 
 #[doc(hidden)]
 pub mod __internal;
 
+mod marker;
+
+mod observer;
+pub use observer::ButlerObserver;
+
+mod spawn;
+pub use spawn::ButlerSpawned;
+
+mod filter;
+pub use filter::ButlerFilter;
+
+mod harness;
+pub use harness::ButlerPluginHarness;
+
+mod timings;
+pub use timings::{ButlerSystemTimings, SystemTiming};
+
+#[cfg(feature = "serde")]
+pub mod file;
+
+#[cfg(feature = "record")]
+pub mod record;
+
 /// Configures a plugin to be usable within bevy_butler's various macros
 /// as a `plugin` argument.
 ///
@@ -30,6 +66,32 @@ pub mod __internal;
 ///     }
 /// }
 /// ```
+///
+/// Using a type that isn't a butler plugin as a `plugin` target is a compile error.
+/// ```rust,compile_fail
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// struct NotAButlerPlugin;
+///
+/// // error: `NotAButlerPlugin` is not a #[butler_plugin]
+/// #[add_system(plugin = NotAButlerPlugin, schedule = Update)]
+/// fn hello_world() {}
+/// ```
+///
+/// A public butler plugin can also be named as a `plugin` target from other crates, which then
+/// add their entries to it. With the `codegen` backend, their tables have to be submitted before
+/// the plugin is built, as described in [`include_butler_entries!`].
+///
+/// # Arguments
+/// ## `instrument`
+/// Instruments every system added to this plugin with [`#[add_system]`](add_system), as if each
+/// had the `instrument` flag. Each system runs inside a `butler_system` tracing span with the
+/// plugin and system names, and records its runs in [`ButlerSystemTimings`] if that resource exists.
+/// ```rust
+/// # use bevy_butler::*;
+/// #[butler_plugin(instrument)]
+/// struct GameplayPlugin;
+/// ```
 pub use bevy_butler_proc_macro::butler_plugin;
 
 /// Registers a system to a [`#[butler_plugin]`](butler_plugin)-annotated [`Plugin`](bevy_app::prelude::Plugin).
@@ -67,7 +129,43 @@ pub use bevy_butler_proc_macro::butler_plugin;
 /// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this system to.
 ///
 /// ## `schedule` (Required)
-/// A [`Schedule`](bevy_ecs::prelude::Schedule) to run this system under.
+/// A [`Schedule`](bevy_ecs::prelude::Schedule) to run this system under. Can be omitted if `fixed` is set.
+///
+/// ## `fixed`
+/// Runs the system in [`FixedUpdate`](bevy_app::prelude::FixedUpdate). Cannot be used with `schedule`.
+///
+/// ## `every`
+/// Only runs the system once every given [`Duration`](core::time::Duration), measured with `Time`.
+/// Requires `TimePlugin`. Combined with `fixed`, the interval is measured in fixed timesteps.
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system(plugin = MyPlugin, schedule = Update, every = Duration::from_millis(250))]
+/// fn autosave() {
+///     info!("Saving...");
+/// }
+/// ```
+///
+/// ## `every_frames`
+/// Only runs the system once every `N` times the schedule runs. Cannot be used with `every`.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[add_system(plugin = MyPlugin, fixed, every_frames = 4, run_if = resource_exists::<Time>)]
+/// fn update_pathfinding() {
+///     info!("Recalculating paths");
+/// }
+/// ```
+///
+/// ## `instrument`
+/// Runs the system inside a `butler_system` tracing span, with the plugin and system names as fields.
+/// If [`ButlerSystemTimings`] was inserted before the plugin was built, the system's run count and
+/// durations are also recorded there.
 ///
 /// ## `generics`
 /// A list of generic arguments to register the system with. Used to register a generic system for multiple
@@ -120,7 +218,8 @@ pub use bevy_butler_proc_macro::butler_plugin;
 ///
 /// ## System transforms
 /// Any attribute that doesn't match the above is assumed to be a system transform function, like [`run_if`](bevy_ecs::prelude::IntoScheduleConfigs::run_if)
-/// or [`after`](bevy_ecs::prelude::IntoScheduleConfigs::after).
+/// or [`after`](bevy_ecs::prelude::IntoScheduleConfigs::after). Arguments that look like a misspelling
+/// of the above, like `schdule`, are rejected instead.
 /// ```rust
 /// # use std::fmt::Display;
 /// # use bevy_butler::*;
@@ -147,6 +246,90 @@ pub use bevy_butler_proc_macro::butler_plugin;
 ///
 pub use bevy_butler_proc_macro::add_system;
 
+/// Adds a list of systems to a [`#[butler_plugin]`](butler_plugin) as a single chained
+/// [`add_systems`](bevy_app::prelude::App::add_systems) call, so they run in the given order.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// fn read_input() {}
+/// fn move_player() {}
+/// fn resolve_collisions() {}
+///
+/// butler_chain!(
+///     plugin = MyPlugin,
+///     schedule = Update,
+///     [read_input, move_player, resolve_collisions]
+/// );
+/// ```
+///
+/// An identical invocation later in the same module, or in a module declared after it, is
+/// rejected as a duplicate.
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register these systems to.
+///
+/// ## `schedule` (Required)
+/// A [`Schedule`](bevy_ecs::prelude::Schedule) to run these systems under.
+///
+/// ## System transforms
+/// Any other arguments are applied to the chain as a whole, the same way as
+/// [`#[add_system]`](add_system), such as `run_if = resource_exists::<Level>`.
+pub use bevy_butler_proc_macro::butler_chain;
+
+/// Spawns the bundle returned by the annotated function in the given schedule, upon the given
+/// [`#[butler_plugin]`](butler_plugin) being built.
+///
+/// The function may return a single [`Bundle`](bevy_ecs::bundle::Bundle) or a
+/// [`Vec`](alloc::vec::Vec) of bundles, and can take any system parameters.
+///
+/// # Usage
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct LevelPlugin;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Component)]
+/// # struct Coin;
+/// #[spawn(plugin = LevelPlugin, schedule = Startup, name)]
+/// fn player() -> impl Bundle {
+///     (Player, Transform::from_xyz(0.0, 0.0, 10.0))
+/// }
+///
+/// #[spawn(plugin = LevelPlugin, schedule = Startup, marker)]
+/// fn coins() -> Vec<(Coin, Transform)> {
+///     (0..4).map(|i| (Coin, Transform::from_xyz(i as f32, 0.0, 0.0))).collect()
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to spawn the bundle for.
+///
+/// ## `schedule` (Required)
+/// The schedule to spawn the bundle in, such as `Startup` or `OnEnter(GameState::InGame)`.
+///
+/// ## `generics`
+/// A list of generic arguments to call the function with.
+///
+/// ## `marker`
+/// Inserts a [`ButlerSpawned<Plugin>`](ButlerSpawned) marker on every spawned entity, so they
+/// can be found or despawned as a group.
+///
+/// ## `name`
+/// Inserts a [`Name`](bevy_ecs::name::Name) on every spawned entity, matching the function's name.
+///
+/// ## System transforms
+/// Any other arguments are applied to the spawning system, the same way as
+/// [`#[add_system]`](add_system).
+pub use bevy_butler_proc_macro::spawn;
+
 /// Registers an [observer](bevy_ecs::prelude::Observer) function to a [`#[butler_plugin]`](butler_plugin)-annotated [`Plugin`](bevy_app::prelude::Plugin).
 ///
 /// # Usage
@@ -198,11 +381,60 @@ pub use bevy_butler_proc_macro::add_system;
 /// ## `generics`
 /// A list of generic arguments to register the observer with. Used to register a generic observer for multiple
 /// different types.
+///
+/// ## `on_component`
+/// Instead of adding a global observer, attaches the observer to every entity that gains the given
+/// [`Component`](bevy_ecs::prelude::Component). The observer is despawned when the component is removed.
+///
+/// The global observers that attach and detach it are named after the observer function, with an
+/// ` (attach)` or ` (detach)` suffix, and marked with [`ButlerObserver`] like the attached ones.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Component)]
+/// # struct Health(u32);
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(Event)]
+/// struct Damage(u32);
+///
+/// #[add_observer(plugin = MyPlugin, on_component = Player)]
+/// fn take_damage(damage: Trigger<Damage>, mut health: Query<&mut Health>) {
+///     if let Ok(mut health) = health.get_mut(damage.target()) {
+///         health.0 = health.0.saturating_sub(damage.0);
+///     }
+/// }
+/// ```
+///
+/// ## `on_spawn_of`
+/// Like `on_component`, but the observer stays attached until the entity is despawned,
+/// even if the component is removed. Useful for marker components.
+///
+/// ## `name`
+/// Observer entities are given a [`Name`](bevy_ecs::name::Name) derived from the path of the
+/// observer function, along with a [`ButlerObserver`] marker for the plugin that created them.
+/// The name can be overridden with `name`.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Event)]
+/// # struct Message;
+/// #[add_observer(plugin = MyPlugin, name = "Message receiver")]
+/// fn receive_message(message: Trigger<Message>) {}
+/// ```
 pub use bevy_butler_proc_macro::add_observer;
 
 /// Registers the annotated [`Resource`](bevy_ecs::prelude::Resource) to a [`#[butler_plugin]`](butler_plugin) and
 /// initializes it upon the plugin being added.
 ///
+/// In debug builds, a warning is logged if the same resource is registered by more than one
+/// butler plugin. The same applies to [`#[add_event]`](add_event) and [`#[insert_state]`](insert_state).
+///
 /// # Usage
 /// ## On a struct
 /// ```rust
@@ -267,6 +499,36 @@ pub use bevy_butler_proc_macro::add_observer;
 /// struct Message(String);
 /// ```
 ///
+/// ## `init_with`
+/// A function or closure that takes `&mut World` and returns the initial value of the resource,
+/// for resources that depend on the rest of the world. The initializer can also return a
+/// `Result<Resource, E>` where `E` implements [`Display`](core::fmt::Display). Cannot be used with `init`.
+///
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Resource)]
+/// # struct Seed(u64);
+/// #[derive(Resource)]
+/// #[insert_resource(plugin = MyPlugin, init_with = Rng::from_seed)]
+/// struct Rng(u64);
+///
+/// impl Rng {
+///     fn from_seed(world: &mut World) -> Result<Self, &'static str> {
+///         world.get_resource::<Seed>().map(|seed| Rng(seed.0)).ok_or("no seed")
+///     }
+/// }
+/// ```
+///
+/// ## `on_init_error`
+/// What to do when the `init_with` initializer returns an error. Both cases report the error
+/// along with the resource and plugin names.
+/// - `on_init_error = panic` (default): panics while building the plugin.
+/// - `on_init_error = skip`: logs the error and leaves the resource uninserted.
+///   `env_prefix`, `persist`, `trace_changes` and `reflect` then leave the missing resource alone.
+///
 /// ## `generics`
 /// A list of generic arguments to register the resource with. Used to register a generic resource for multiple
 /// different types.
@@ -285,6 +547,133 @@ pub use bevy_butler_proc_macro::add_observer;
 /// #[insert_resource(plugin = MyPlugin, non_send)]
 /// struct MyNonSendResource;
 /// ```
+///
+/// ## `reflect`
+/// Registers the resource to the type registry along with
+/// [`ReflectResource`](bevy_ecs::reflect::ReflectResource), so it does not need a
+/// separate [`#[register_type]`](register_type). The resource must implement `Reflect`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource, Reflect, Default)]
+/// #[insert_resource(plugin = MyPlugin, reflect)]
+/// struct Score(u32);
+/// ```
+///
+/// ## `from_file`
+/// *Requires the `ron`, `json` or `toml` feature, depending on the file format.*
+///
+/// Deserializes the initial value of the resource from the given file when the plugin is built.
+/// The format is chosen by the file extension. If the file does not exist, a warning is logged
+/// and the resource falls back to `init` or its [`Default`] value. Any other error will panic
+/// with the file path and the resource type.
+/// ```rust,ignore
+/// #[derive(Resource, Deserialize, Default)]
+/// #[insert_resource(plugin = MyPlugin, from_file = "config/physics.ron")]
+/// struct PhysicsConfig {
+///     gravity: f32,
+/// }
+/// ```
+///
+/// ## `env_prefix`
+/// Allows the fields of the resource to be overridden by environment variables or command-line
+/// arguments after its initial value is computed. With `env_prefix = "GAME_PHYSICS"`, the field
+/// `gravity` can be set with `GAME_PHYSICS_GRAVITY=12` or `--game-physics-gravity=12`.
+/// Command-line arguments take priority over environment variables.
+///
+/// The resource must be a struct implementing either `Reflect`, whose fields of primitive types
+/// and [`String`](alloc::string::String) can be overridden, or `Serialize` and `Deserialize`
+/// with the `json` feature, whose values are parsed as JSON and otherwise taken as strings.
+/// Each applied override is logged. Building the plugin will panic if an override doesn't match
+/// any field, listing the fields it expected, or if a value can't be parsed. Requires the `std`
+/// feature.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource, Reflect, Default)]
+/// #[insert_resource(plugin = MyPlugin, env_prefix = "GAME_PHYSICS")]
+/// struct PhysicsConfig {
+///     gravity: f32,
+///     substeps: u8,
+/// }
+/// ```
+///
+/// ## `env_ignore_unknown`
+/// Skips overrides for `env_prefix` that don't match any field, instead of panicking. This allows
+/// the prefix to overlap with the prefix of another resource, like `GAME_AUDIO` and
+/// `GAME_AUDIO_MIXER`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource, Reflect, Default)]
+/// #[insert_resource(plugin = MyPlugin, env_prefix = "GAME_AUDIO", env_ignore_unknown)]
+/// struct AudioConfig {
+///     volume: f32,
+/// }
+/// ```
+///
+/// ## `persist`
+/// *Requires the `ron`, `json` or `toml` feature, depending on the file format.*
+///
+/// Keeps the resource in sync with the given file. When the plugin is built, the resource is
+/// loaded from the file if it exists, otherwise it falls back to `init` or its [`Default`] value.
+/// The resource is written back to the file when [`AppExit`](bevy_app::AppExit) is sent, in a
+/// schedule that runs after [`Last`](bevy_app::Last) so exits sent from any schedule of the frame
+/// are seen. Save errors are logged instead of panicking.
+///
+/// Unlike `from_file`, a file that can't be loaded, like one saved by an older version of the
+/// resource or cut off while saving, doesn't panic. The error is logged and the resource falls
+/// back to its initial value, which replaces the file on the next save.
+///
+/// `persist` cannot be combined with `from_file` or `non_send`.
+/// ```rust,ignore
+/// #[derive(Resource, Serialize, Deserialize, Default)]
+/// #[insert_resource(plugin = MyPlugin, persist = "settings.ron")]
+/// struct UserSettings {
+///     volume: f32,
+/// }
+/// ```
+///
+/// ## `persist_debounce`
+/// Also saves a `persist`ed resource whenever it changes, once it has gone unchanged for the
+/// given [`Duration`](core::time::Duration).
+/// ```rust,ignore
+/// #[derive(Resource, Serialize, Deserialize, Default)]
+/// #[insert_resource(
+///     plugin = MyPlugin,
+///     persist = "settings.ron",
+///     persist_debounce = Duration::from_secs(1),
+/// )]
+/// struct UserSettings {
+///     volume: f32,
+/// }
+/// ```
+///
+/// ## `trace_changes`
+/// In debug builds, logs every frame in which the resource was changed. If the resource is
+/// registered with [`ReflectResource`](bevy_ecs::reflect::ReflectResource) (see `reflect`), the
+/// log also lists each field that changed with its old and new values, with fields of nested
+/// structs listed by their path, like `audio.volume`. With bevy's
+/// `track_location` feature enabled, the log includes the location that last changed the resource.
+///
+/// Cannot be used with `non_send`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Resource, Reflect, Default)]
+/// #[insert_resource(plugin = MyPlugin, reflect, trace_changes)]
+/// struct CameraConfig {
+///     fov: f32,
+/// }
+/// ```
 pub use bevy_butler_proc_macro::insert_resource;
 
 /// Registers the annotated [`Event`](bevy_ecs::prelude::Event) upon the
@@ -337,6 +726,81 @@ pub use bevy_butler_proc_macro::insert_resource;
 /// ## `generics`
 /// A list of generic arguments to register the event with. Used to register a generic event for multiple
 /// different types.
+///
+/// ## `reflect`
+/// Registers the event and its [`Events`](bevy_ecs::event::Events) resource to the type registry,
+/// so it does not need a separate [`#[register_type]`](register_type). The event must implement `Reflect`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Event, Reflect)]
+/// #[add_event(plugin = MyPlugin, reflect)]
+/// struct PlayerScored(u32);
+/// ```
+///
+/// ## `manual_update`
+/// Adds the [`Events`](bevy_ecs::event::Events) resource without registering it for automatic updates,
+/// so events are only cleared when you call [`Events::update`](bevy_ecs::event::Events::update) yourself.
+/// Useful for events that are consumed in `FixedUpdate`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Event)]
+/// #[add_event(plugin = MyPlugin, manual_update)]
+/// struct Jump;
+///
+/// #[add_system(plugin = MyPlugin, schedule = FixedPostUpdate)]
+/// fn clear_jumps(mut jumps: ResMut<Events<Jump>>) {
+///     jumps.update();
+/// }
+/// ```
+///
+/// ## `debug_log`
+/// Adds a system that logs every event at the `DEBUG` level using its `Reflect` implementation.
+///
+/// ## `trigger_only`
+/// Skips adding the [`Events`](bevy_ecs::event::Events) resource, for events that are only ever
+/// used with [`Commands::trigger`](bevy_ecs::prelude::Commands::trigger) and observers.
+/// Systems that read or write the event through an [`EventReader`](bevy_ecs::prelude::EventReader)
+/// or [`EventWriter`](bevy_ecs::prelude::EventWriter) panic in both debug and release builds,
+/// since the resource they use is missing.
+/// Cannot be used with `manual_update` or `debug_log`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Event)]
+/// #[add_event(plugin = MyPlugin, trigger_only)]
+/// struct Explode;
+/// ```
+///
+/// ## `record` / `record_capacity`
+/// *Requires the `record` feature.*
+///
+/// Adds an [`EventRecorder`](crate::record::EventRecorder) that keeps every event written during
+/// the last `record_capacity` frames (600 by default). The recording can be saved to a file and
+/// later fed back through an [`EventWriter`](bevy_ecs::prelude::EventWriter) by inserting an
+/// [`EventReplay`](crate::record::EventReplay). Replayed events aren't recorded again. The event
+/// must implement `Clone`, `Serialize` and `Deserialize`, and saving requires the `ron` or `json`
+/// feature.
+/// ```rust,ignore
+/// #[derive(Event, Clone, Serialize, Deserialize)]
+/// #[add_event(plugin = MyPlugin, record)]
+/// struct Jump;
+///
+/// fn save_recording(recorder: Res<EventRecorder<Jump>>) {
+///     recorder.save("jumps.ron").unwrap();
+/// }
+///
+/// fn start_replay(mut commands: Commands) {
+///     commands.insert_resource(EventReplay::<Jump>::load("jumps.ron").unwrap());
+/// }
+/// ```
 pub use bevy_butler_proc_macro::add_event;
 
 /// Registers the annotated `Reflect` type into the app's type registry for reflection.
@@ -387,6 +851,324 @@ pub use bevy_butler_proc_macro::add_event;
 /// different types.
 pub use bevy_butler_proc_macro::register_type;
 
+/// Registers the annotated [`Component`](bevy_ecs::prelude::Component) to the world
+/// upon the given [`#[butler_plugin]`](butler_plugin) being built.
+///
+/// # Usage
+/// ## On a struct
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Component)]
+/// #[register_component(plugin = MyPlugin)]
+/// struct Health(u32);
+/// ```
+/// ## On an imported type
+/// ```rust
+/// # use bevy_butler::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # mod my_mod {
+/// # use bevy::prelude::*;
+/// # #[derive(Component)]
+/// # pub struct Health(u32);
+/// # }
+/// #[register_component(plugin = MyPlugin)]
+/// use my_mod::Health;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this component to.
+///
+/// ## `generics`
+/// A list of generic arguments to register the component with. Used to register a generic component for multiple
+/// different types.
+///
+/// ## `reflect`
+/// Registers the component to the type registry along with
+/// [`ReflectComponent`](bevy_ecs::reflect::ReflectComponent). The component must implement `Reflect`.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Component, Reflect)]
+/// #[register_component(plugin = MyPlugin, reflect)]
+/// struct Health(u32);
+/// ```
+///
+/// ## `on_add` / `on_insert` / `on_replace` / `on_remove` / `on_despawn`
+/// Registers a [component hook](bevy_ecs::component::ComponentHooks) for the component.
+/// The hook must be a `fn(DeferredWorld, HookContext)`.
+///
+/// If the component already has a hook of the same kind, such as one set through
+/// `#[component(on_add = ...)]`, building the plugin will panic.
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::{component::HookContext, world::DeferredWorld};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// fn player_added(world: DeferredWorld, ctx: HookContext) {
+///     info!("Player {} joined", ctx.entity);
+/// }
+///
+/// #[derive(Component)]
+/// #[register_component(plugin = MyPlugin, on_add = player_added)]
+/// struct Player;
+/// ```
+///
+/// ## `requires`
+/// A list of components to register as [required components](bevy_ecs::prelude::Component#required-components)
+/// of the annotated component. Each required component must implement [`Default`].
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Component, Default)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Default)]
+/// struct Mana(u32);
+///
+/// #[derive(Component)]
+/// #[register_component(plugin = MyPlugin, requires = [Health, Mana])]
+/// struct Player;
+/// ```
+pub use bevy_butler_proc_macro::register_component;
+
+/// Initializes the annotated `Asset` type upon the given [`#[butler_plugin]`](butler_plugin) being built,
+/// using `AssetApp::init_asset`.
+///
+/// bevy-butler does not depend on `bevy_asset` itself. The generated code uses `bevy::asset`
+/// or `bevy_asset`, depending on which one your crate depends on.
+///
+/// # Usage
+/// ## On a struct
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Asset, TypePath)]
+/// #[init_asset(plugin = MyPlugin)]
+/// struct LevelData {
+///     tiles: Vec<u8>,
+/// }
+/// ```
+///
+/// ## On an imported type
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// mod my_mod {
+///     # use bevy::prelude::*;
+///     #[derive(Asset, TypePath)]
+///     pub struct LevelData;
+/// }
+///
+/// #[init_asset(plugin = MyPlugin)]
+/// use my_mod::LevelData;
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this asset to.
+///
+/// ## `generics`
+/// A list of generic arguments to register the asset with. Used to register a generic asset for multiple
+/// different types.
+pub use bevy_butler_proc_macro::init_asset;
+
+/// Registers the annotated `AssetLoader` upon the given [`#[butler_plugin]`](butler_plugin) being built.
+///
+/// By default, the loader is created from [`FromWorld`](bevy_ecs::world::FromWorld) with
+/// `AssetApp::init_asset_loader`, which includes any loader implementing [`Default`].
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Asset, TypePath)]
+/// # struct LevelData(Vec<u8>);
+/// #[derive(Default)]
+/// #[register_asset_loader(plugin = MyPlugin)]
+/// struct LevelLoader;
+///
+/// impl AssetLoader for LevelLoader {
+///     type Asset = LevelData;
+///     // ...
+/// #   type Settings = ();
+/// #   type Error = std::io::Error;
+/// #
+/// #   async fn load(
+/// #       &self,
+/// #       reader: &mut dyn Reader,
+/// #       _settings: &(),
+/// #       _load_context: &mut LoadContext<'_>,
+/// #   ) -> Result<LevelData, Self::Error> {
+/// #       let mut bytes = Vec::new();
+/// #       reader.read_to_end(&mut bytes).await?;
+/// #       Ok(LevelData(bytes))
+/// #   }
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this loader to.
+///
+/// ## `init`
+/// An expression to create the loader with, passed to `AssetApp::register_asset_loader`.
+/// For loaders that don't implement [`FromWorld`](bevy_ecs::world::FromWorld).
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Asset, TypePath)]
+/// # struct LevelData(Vec<u8>);
+/// #[register_asset_loader(plugin = MyPlugin, init = LevelLoader { compressed: true })]
+/// struct LevelLoader {
+///     compressed: bool,
+/// }
+/// # impl AssetLoader for LevelLoader {
+/// #   type Asset = LevelData;
+/// #   type Settings = ();
+/// #   type Error = std::io::Error;
+/// #
+/// #   async fn load(
+/// #       &self,
+/// #       reader: &mut dyn Reader,
+/// #       _settings: &(),
+/// #       _load_context: &mut LoadContext<'_>,
+/// #   ) -> Result<LevelData, Self::Error> {
+/// #       let mut bytes = Vec::new();
+/// #       reader.read_to_end(&mut bytes).await?;
+/// #       Ok(LevelData(bytes))
+/// #   }
+/// # }
+/// ```
+///
+/// ## `generics`
+/// A list of generic arguments to register the loader with.
+pub use bevy_butler_proc_macro::register_asset_loader;
+
+/// Registers the annotated asset `Process` implementation upon the given
+/// [`#[butler_plugin]`](butler_plugin) being built, using `AssetApp::register_asset_processor`.
+///
+/// Like `AssetApp::register_asset_processor`, this does nothing unless the app processes its
+/// assets, which requires the `asset_processor` feature of Bevy and `AssetMode::Processed`.
+///
+/// By default, the processor is created from [`FromWorld`](bevy_ecs::world::FromWorld), which
+/// includes any processor implementing [`Default`].
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::{
+/// #     io::{Reader, Writer},
+/// #     meta::AssetMeta,
+/// #     processor::{Process, ProcessContext, ProcessError},
+/// #     AssetLoader, AsyncWriteExt, LoadContext,
+/// # };
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// # #[derive(Asset, TypePath)]
+/// # struct LevelData(Vec<u8>);
+/// # #[derive(Default)]
+/// # struct LevelLoader;
+/// # impl AssetLoader for LevelLoader {
+/// #   type Asset = LevelData;
+/// #   type Settings = ();
+/// #   type Error = std::io::Error;
+/// #
+/// #   async fn load(
+/// #       &self,
+/// #       reader: &mut dyn Reader,
+/// #       _settings: &(),
+/// #       _load_context: &mut LoadContext<'_>,
+/// #   ) -> Result<LevelData, Self::Error> {
+/// #       let mut bytes = Vec::new();
+/// #       reader.read_to_end(&mut bytes).await?;
+/// #       Ok(LevelData(bytes))
+/// #   }
+/// # }
+/// #[derive(Default)]
+/// #[register_asset_processor(plugin = MyPlugin, default_extension = "level")]
+/// struct LevelProcessor;
+///
+/// impl Process for LevelProcessor {
+///     type Settings = ();
+///     type OutputLoader = LevelLoader;
+///     // ...
+/// #   async fn process(
+/// #       &self,
+/// #       context: &mut ProcessContext<'_>,
+/// #       _meta: AssetMeta<(), Self>,
+/// #       writer: &mut Writer,
+/// #   ) -> Result<(), ProcessError> {
+/// #       let path = context.path().clone_owned();
+/// #       writer
+/// #           .write_all(context.asset_bytes())
+/// #           .await
+/// #           .map_err(|e| ProcessError::AssetWriterError { path, err: e.into() })
+/// #   }
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this processor to.
+///
+/// ## `init`
+/// An expression to create the processor with, for processors that don't implement
+/// [`FromWorld`](bevy_ecs::world::FromWorld).
+///
+/// ## `default_extension`
+/// Makes this the default processor of assets with the given file extension, using
+/// `AssetApp::set_default_asset_processor`.
+///
+/// ## `generics`
+/// A list of generic arguments to register the processor with.
+pub use bevy_butler_proc_macro::register_asset_processor;
+
+/// Registers the reflection data of the annotated `Asset` type, and its `Handle`, upon the given
+/// [`#[butler_plugin]`](butler_plugin) being built, using `AssetApp::register_asset_reflect`.
+///
+/// # Usage
+/// ```rust
+/// # use bevy_butler::*;
+/// # use bevy::prelude::*;
+/// # #[butler_plugin]
+/// # struct MyPlugin;
+/// #[derive(Asset, Reflect)]
+/// #[init_asset(plugin = MyPlugin)]
+/// #[register_asset_reflect(plugin = MyPlugin)]
+/// struct LevelData {
+///     tiles: Vec<u8>,
+/// }
+/// ```
+///
+/// # Arguments
+/// ## `plugin` (Required)
+/// A [`Plugin`](bevy_app::prelude::Plugin) annotated with [`#[butler_plugin]`](butler_plugin) to register this asset to.
+///
+/// ## `generics`
+/// A list of generic arguments to register the asset with.
+pub use bevy_butler_proc_macro::register_asset_reflect;
+
 /// Implements `PluginGroup` and configures it to be used with [`add_plugin`]/[`add_plugin_group`].
 ///
 /// # Usage
@@ -546,15 +1328,154 @@ pub use bevy_butler_proc_macro::add_plugin_group;
 /// A list of generic arguments to register the state with. Used to register a generic state for multiple different types.
 pub use bevy_butler_proc_macro::insert_state;
 
-#[cfg(all(target_arch = "wasm32", not(feature = "wasm-experimental")))]
+/// Turns the annotated function into a test that builds a headless [`App`](bevy_app::prelude::App)
+/// with the given plugins, updates it, then hands its `&mut World` to the function for assertions.
+///
+/// # Usage
+/// ```rust,ignore
+/// #[butler_plugin]
+/// struct MyPlugin;
+///
+/// #[derive(Resource, Default)]
+/// #[insert_resource(plugin = MyPlugin)]
+/// struct Counter(u32);
+///
+/// #[add_system(plugin = MyPlugin, schedule = Update)]
+/// fn count(mut counter: ResMut<Counter>) {
+///     counter.0 += 1;
+/// }
+///
+/// #[butler_test(plugins = [MyPlugin], updates = 5)]
+/// fn counts_every_update(world: &mut World) {
+///     assert_eq!(world.resource::<Counter>().0, 5);
+/// }
+/// ```
+///
+/// Other attributes on the function, like `#[should_panic]`, are applied to the generated test.
+/// The function can also take no arguments, if it only needs the app to run without panicking.
+///
+/// # Arguments
+/// ## `plugins`
+/// A list of plugins to add to the app, in order.
+///
+/// ## `updates`
+/// The number of times to update the app. If not set, the app is updated until
+/// [`AppExit`](bevy_app::prelude::AppExit) is sent. Sending `AppExit` also stops the
+/// updates early when this is set.
+///
+/// ## `timeout`
+/// A [`Duration`](core::time::Duration) after which the test fails, instead of hanging.
+/// Defaults to 10 seconds. Ignored on wasm and without the `std` feature, where tests can't be
+/// run on another thread.
+pub use bevy_butler_proc_macro::butler_test;
+
+/// Includes the registration table generated by `bevy-butler-build`, for the `codegen` backend.
+///
+/// The `codegen` backend registers entries through a table generated by a build script, instead of
+/// linker sections (`linkme`) or static constructors (`inventory`), so it works on any target,
+/// including WebAssembly. To use it, enable the `codegen` feature, call `bevy_butler_build::generate()`
+/// from `build.rs`, and invoke this macro once at the root of the crate.
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main() {
+///     bevy_butler_build::generate();
+/// }
+///
+/// // lib.rs or main.rs
+/// bevy_butler::include_butler_entries!();
+/// ```
+///
+/// Only crates whose build script calls `bevy_butler_build::generate()` use the table. Other crates
+/// keep registering their entries through the default backend, even when the `codegen` feature is
+/// enabled by another crate, and both kinds of entries can be added to the same plugin.
+///
+/// The table is submitted when a [`#[butler_plugin]`](butler_plugin) or
+/// [`#[butler_plugin_group]`](butler_plugin_group) of the same crate is built. Entries added to the
+/// plugins of another crate are only found once their table is submitted, so a plugin that was
+/// built before then would miss them. To avoid this, the crate that builds the app lists the other
+/// crates with a table, and submits every table with [`submit_butler_entries!`] before adding
+/// any plugins. Submitting an entry to a plugin that was already built panics.
+///
+/// ```rust,ignore
+/// // main.rs
+/// bevy_butler::include_butler_entries!(my_core_crate, my_other_crate);
+///
+/// fn main() {
+///     bevy_butler::submit_butler_entries!();
+///     App::new().add_plugins(my_core_crate::CorePlugin).run();
+/// }
+/// ```
+///
+/// The build script finds entries by scanning the source files of the crate, including the ones
+/// behind `#[cfg_attr(...)]`. Entries in function bodies, or generated by other macros, can't be
+/// found, and fail to compile instead of never being registered.
+#[cfg(feature = "codegen")]
+#[macro_export]
+macro_rules! include_butler_entries {
+    ($($dependency:ident),* $(,)?) => {
+        include!(concat!(env!("OUT_DIR"), "/bevy_butler_entries.rs"));
+
+        #[doc(hidden)]
+        pub fn __butler_codegen_entries(entries: &mut ::bevy_butler::__internal::CodegenEntries) {
+            entries.add_table(__butler_codegen_table);
+            $(entries.add_table($dependency::__butler_codegen_entries);)*
+        }
+    };
+}
+
+/// Submits the table of the calling crate, along with the tables of the crates listed in its
+/// [`include_butler_entries!`], for the `codegen` backend.
+///
+/// Call this before adding any plugins, so that every plugin gets the entries that other crates
+/// add to it. Crates that don't include a table themselves, like integration tests, can submit the
+/// tables of other crates by name instead, with `submit_butler_entries!(my_crate)`.
+#[cfg(feature = "codegen")]
+#[macro_export]
+macro_rules! submit_butler_entries {
+    () => {
+        $crate::_butler_submit_codegen_entries!()
+    };
+    ($($krate:ident),+ $(,)?) => {
+        $($crate::__internal::submit_codegen_entries($krate::__butler_codegen_entries);)+
+    };
+}
+
+#[cfg(not(feature = "codegen"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! include_butler_entries {
+    ($($dependency:ident),* $(,)?) => {
+        ::core::compile_error!(
+            "`include_butler_entries!` requires the `codegen` feature of bevy-butler"
+        );
+    };
+}
+
+#[cfg(not(feature = "codegen"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! submit_butler_entries {
+    ($($krate:ident),* $(,)?) => {
+        ::core::compile_error!(
+            "`submit_butler_entries!` requires the `codegen` feature of bevy-butler"
+        )
+    };
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(any(feature = "wasm-experimental", feature = "codegen"))
+))]
 compile_error!(
     "WebAssembly support in bevy-butler is experimental and buggy.
-If you wish to try it anyways, enable the `wasm-experimental` feature.
+If you wish to try it anyways, enable the `wasm-experimental` feature,
+or use the `codegen` feature to register entries through a build script instead.
 See also: https://github.com/TGRCdev/bevy-butler/issues/3
 "
 );
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", feature = "wasm-experimental"))]
 extern "C" {
     fn __wasm_call_ctors();
 }
@@ -564,7 +1485,7 @@ extern "C" {
 /// tests and it refuses to run on my PC
 ///
 /// I tried man
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", feature = "wasm-experimental"))]
 #[doc(hidden)]
 pub fn _initialize() {
     unsafe {
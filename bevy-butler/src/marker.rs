@@ -0,0 +1,18 @@
+use alloc::vec::Vec;
+
+use bevy_ecs::prelude::*;
+
+/// Returns every entity with the marker component `M`.
+pub(crate) fn marked_entities<M: Component>(world: &mut World) -> Vec<Entity> {
+    world
+        .query_filtered::<Entity, With<M>>()
+        .iter(world)
+        .collect()
+}
+
+/// Despawns every entity with the marker component `M`.
+pub(crate) fn despawn_marked<M: Component>(world: &mut World) {
+    for entity in marked_entities::<M>(world) {
+        world.despawn(entity);
+    }
+}
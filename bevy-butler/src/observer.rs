@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use crate::marker::{despawn_marked, marked_entities};
+
+/// Marks an [`Observer`] entity that was created by the [`#[butler_plugin]`](crate::butler_plugin) `P`
+/// through [`#[add_observer]`](crate::add_observer).
+///
+/// Every observer created by butler is also given a [`Name`](bevy_ecs::name::Name) derived from
+/// the observer function, so it can be identified in world dumps and inspectors.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// #[butler_plugin]
+/// struct MyPlugin;
+///
+/// # #[derive(Event)]
+/// # struct Message;
+/// #[add_observer(plugin = MyPlugin)]
+/// fn receive_message(_message: Trigger<Message>) {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(MyPlugin);
+///
+/// let world = app.world_mut();
+/// assert_eq!(ButlerObserver::<MyPlugin>::entities(world).len(), 1);
+///
+/// ButlerObserver::<MyPlugin>::despawn_all(world);
+/// assert!(ButlerObserver::<MyPlugin>::entities(world).is_empty());
+/// ```
+#[derive(Component)]
+pub struct ButlerObserver<P: 'static>(PhantomData<fn() -> P>);
+
+impl<P: 'static> Default for ButlerObserver<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: 'static> ButlerObserver<P> {
+    /// Returns every observer entity that `P` created.
+    pub fn entities(world: &mut World) -> Vec<Entity> {
+        marked_entities::<Self>(world)
+    }
+
+    /// Despawns every observer entity that `P` created.
+    pub fn despawn_all(world: &mut World) {
+        despawn_marked::<Self>(world);
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use std::path::Path;
+
+use bevy_ecs::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::file::{load_file, save_file, FileError};
+
+/// The events of type `E` that were written during a single frame.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedFrame<E> {
+    /// The frame the events were recorded on, counted from when the recorder was added
+    pub frame: u64,
+    pub events: Vec<E>,
+}
+
+/// Records every event of type `E` into a ring buffer of the last `capacity` frames.
+///
+/// Added by [`#[add_event(record)]`](crate::add_event). Frames without any events are not stored.
+/// Events written by an [`EventReplay<E>`] are not recorded again.
+#[derive(Resource)]
+pub struct EventRecorder<E> {
+    capacity: usize,
+    frame: u64,
+    frames: VecDeque<RecordedFrame<E>>,
+    /// The ids of the events replayed this frame, which are skipped when recording it
+    replayed: Vec<usize>,
+}
+
+impl<E> EventRecorder<E> {
+    /// Creates a recorder that keeps the events of the last `capacity` frames.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            frame: 0,
+            frames: VecDeque::new(),
+            replayed: Vec::new(),
+        }
+    }
+
+    /// Returns the recorded frames, oldest first.
+    pub fn frames(&self) -> impl Iterator<Item = &RecordedFrame<E>> {
+        self.frames.iter()
+    }
+
+    /// Discards every recorded frame.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    fn push(&mut self, events: Vec<E>) {
+        if !events.is_empty() {
+            self.frames.push_back(RecordedFrame {
+                frame: self.frame,
+                events,
+            });
+        }
+
+        // Drop anything that fell out of the window
+        while self
+            .frames
+            .front()
+            .is_some_and(|f| f.frame + self.capacity as u64 <= self.frame)
+        {
+            self.frames.pop_front();
+        }
+
+        self.frame += 1;
+    }
+}
+
+impl<E: Serialize> EventRecorder<E> {
+    /// Writes the recorded frames to `path`. The format is chosen by the file extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FileError> {
+        save_file(&self.frames, path)
+    }
+}
+
+/// Replays previously recorded events of type `E` through [`EventWriter<E>`].
+///
+/// Insert this resource to start a replay. Frames are replayed relative to the first
+/// recorded frame, and the resource removes itself once every frame has been replayed.
+/// The [`EventRecorder<E>`] skips the replayed events, so it only records new ones.
+#[derive(Resource)]
+pub struct EventReplay<E> {
+    frame: u64,
+    frames: VecDeque<RecordedFrame<E>>,
+}
+
+impl<E> EventReplay<E> {
+    /// Creates a replay from a list of recorded frames.
+    pub fn new(frames: impl IntoIterator<Item = RecordedFrame<E>>) -> Self {
+        let frames: VecDeque<_> = frames.into_iter().collect();
+        Self {
+            frame: frames.front().map(|f| f.frame).unwrap_or_default(),
+            frames,
+        }
+    }
+}
+
+impl<E: Clone> EventReplay<E> {
+    /// Creates a replay from everything currently held by `recorder`.
+    pub fn from_recorder(recorder: &EventRecorder<E>) -> Self {
+        Self::new(recorder.frames().cloned())
+    }
+}
+
+impl<E: DeserializeOwned> EventReplay<E> {
+    /// Loads a replay from a file written by [`EventRecorder::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FileError> {
+        load_file::<Vec<RecordedFrame<E>>>(path).map(Self::new)
+    }
+}
+
+pub(crate) fn record_events<E: Event + Clone>(
+    mut events: EventReader<E>,
+    mut recorder: ResMut<EventRecorder<E>>,
+) {
+    let replayed = core::mem::take(&mut recorder.replayed);
+    let events = events
+        .read_with_id()
+        .filter(|(_, id)| !replayed.contains(&id.id))
+        .map(|(event, _)| event.clone())
+        .collect();
+    recorder.push(events);
+}
+
+pub(crate) fn replay_events<E: Event>(
+    mut commands: Commands,
+    mut replay: ResMut<EventReplay<E>>,
+    mut recorder: Option<ResMut<EventRecorder<E>>>,
+    mut writer: EventWriter<E>,
+) {
+    while replay
+        .frames
+        .front()
+        .is_some_and(|f| f.frame <= replay.frame)
+    {
+        let frame = replay.frames.pop_front().unwrap();
+        let ids = writer.write_batch(frame.events);
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.replayed.extend(ids.map(|id| id.id));
+        }
+    }
+
+    if replay.frames.is_empty() {
+        commands.remove_resource::<EventReplay<E>>();
+    }
+    replay.frame += 1;
+}
@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use bevy_ecs::prelude::*;
+
+use crate::marker::{despawn_marked, marked_entities};
+
+/// Marks an entity that was spawned for the [`#[butler_plugin]`](crate::butler_plugin) `P`
+/// through [`#[spawn]`](crate::spawn) with the `marker` flag.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// #[butler_plugin]
+/// struct LevelPlugin;
+///
+/// #[spawn(plugin = LevelPlugin, schedule = Startup, marker)]
+/// fn walls() -> Vec<Transform> {
+///     vec![Transform::from_xyz(-10.0, 0.0, 0.0), Transform::from_xyz(10.0, 0.0, 0.0)]
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(LevelPlugin);
+/// app.update();
+///
+/// let world = app.world_mut();
+/// assert_eq!(ButlerSpawned::<LevelPlugin>::entities(world).len(), 2);
+///
+/// ButlerSpawned::<LevelPlugin>::despawn_all(world);
+/// assert!(ButlerSpawned::<LevelPlugin>::entities(world).is_empty());
+/// ```
+#[derive(Component)]
+pub struct ButlerSpawned<P: 'static>(PhantomData<fn() -> P>);
+
+impl<P: 'static> Default for ButlerSpawned<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: 'static> ButlerSpawned<P> {
+    /// Returns every entity that `P` spawned.
+    pub fn entities(world: &mut World) -> Vec<Entity> {
+        marked_entities::<Self>(world)
+    }
+
+    /// Despawns every entity that `P` spawned.
+    pub fn despawn_all(world: &mut World) {
+        despawn_marked::<Self>(world);
+    }
+}
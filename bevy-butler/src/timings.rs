@@ -0,0 +1,101 @@
+use alloc::sync::Arc;
+use bevy_platform::{collections::HashMap, sync::Mutex};
+use core::time::Duration;
+
+use bevy_ecs::prelude::*;
+
+/// The run count and durations of a single instrumented system.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SystemTiming {
+    /// The type name of the [`#[butler_plugin]`](crate::butler_plugin) that registered the system.
+    pub plugin: &'static str,
+    /// The number of times the system has run.
+    pub runs: u64,
+    /// The total time spent running the system.
+    pub total: Duration,
+    /// The time spent in the most recent run of the system.
+    pub last: Duration,
+}
+
+impl SystemTiming {
+    /// The average time spent per run, or zero if the system hasn't run yet.
+    pub fn average(&self) -> Duration {
+        match self.runs {
+            0 => Duration::ZERO,
+            runs => self.total.div_f64(runs as f64),
+        }
+    }
+}
+
+/// Collects the [`SystemTiming`] of every system instrumented by butler, through
+/// `#[butler_plugin(instrument)]` or `#[add_system(instrument)]`.
+///
+/// Timings are only collected for systems registered after this resource is inserted,
+/// so it should be inserted before adding any plugins.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_butler::*;
+/// #[butler_plugin(instrument)]
+/// struct GameplayPlugin;
+///
+/// #[add_system(plugin = GameplayPlugin, schedule = Update)]
+/// fn move_player() {}
+///
+/// let mut app = App::new();
+/// app.init_resource::<ButlerSystemTimings>()
+///     .add_plugins(GameplayPlugin);
+/// app.update();
+///
+/// let timings = app.world().resource::<ButlerSystemTimings>();
+/// for (system, timing) in timings.iter() {
+///     info!("{system}: {} runs, {:?} on average", timing.runs, timing.average());
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct ButlerSystemTimings {
+    systems: HashMap<&'static str, Arc<Mutex<SystemTiming>>>,
+}
+
+impl ButlerSystemTimings {
+    /// Returns the timing of the system with the given name, such as `my_crate::move_player`.
+    pub fn get(&self, system: &str) -> Option<SystemTiming> {
+        self.systems
+            .get(system)
+            .map(|timing| *timing.lock().unwrap())
+    }
+
+    /// Returns the name and timing of every instrumented system.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, SystemTiming)> + '_ {
+        self.systems
+            .iter()
+            .map(|(system, timing)| (*system, *timing.lock().unwrap()))
+    }
+
+    /// Resets the timing of every system.
+    pub fn reset(&mut self) {
+        for timing in self.systems.values() {
+            let mut timing = timing.lock().unwrap();
+            *timing = SystemTiming {
+                plugin: timing.plugin,
+                ..Default::default()
+            };
+        }
+    }
+
+    pub(crate) fn track(
+        &mut self,
+        system: &'static str,
+        plugin: &'static str,
+    ) -> Arc<Mutex<SystemTiming>> {
+        self.systems
+            .entry(system)
+            .or_insert_with(|| {
+                Arc::new(Mutex::new(SystemTiming {
+                    plugin,
+                    ..Default::default()
+                }))
+            })
+            .clone()
+    }
+}
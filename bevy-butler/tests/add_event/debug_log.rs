@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::{capture_logs, log_plugin};
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Event, Reflect)]
+#[add_event(plugin = MyPlugin, debug_log)]
+struct PlayerMoved {
+    x: f32,
+    y: f32,
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn move_player(mut moved: EventWriter<PlayerMoved>) {
+    moved.write(PlayerMoved { x: 1.0, y: 2.0 });
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let logs = capture_logs(|| app.update());
+    assert!(
+        logs.iter()
+            .any(|log| log.contains("PlayerMoved") && log.contains("x: 1.0, y: 2.0")),
+        "{logs:?}"
+    );
+}
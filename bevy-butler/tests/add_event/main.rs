@@ -1,5 +1,9 @@
 include!("../common.rs");
 
+mod debug_log;
 mod event;
 mod event_enum;
 mod generic_event;
+mod manual_update;
+mod reflect_event;
+mod trigger_only;
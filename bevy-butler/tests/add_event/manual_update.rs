@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Event)]
+#[add_event(plugin = MyPlugin, manual_update)]
+struct Tick;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    app.world_mut().send_event(Tick);
+    app.update();
+    app.update();
+    app.update();
+
+    // Events without automatic updates are never cleared
+    let mut events = app.world_mut().resource_mut::<Events<Tick>>();
+    assert_eq!(events.len(), 1);
+
+    events.update();
+    events.update();
+    assert!(events.is_empty());
+}
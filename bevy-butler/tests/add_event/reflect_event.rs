@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Event, Reflect)]
+#[add_event(plugin = MyPlugin, reflect)]
+struct PlayerScored(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Startup)]
+fn read_type_registration(registry: Res<AppTypeRegistry>) {
+    let registry = registry.read();
+    registry
+        .get_with_short_type_path("PlayerScored")
+        .expect("PlayerScored was not registered to the type registry");
+    let events = registry
+        .get_with_short_type_path("Events<PlayerScored>")
+        .expect("Events<PlayerScored> was not registered to the type registry");
+
+    assert!(events.data::<ReflectResource>().is_some());
+}
+
+#[test]
+fn test() {
+    App::new()
+        .add_plugins(log_plugin())
+        .add_plugins(MyPlugin)
+        .run();
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Event)]
+#[add_event(plugin = MyPlugin, trigger_only)]
+struct Explode;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Explosions(u8);
+
+#[add_observer(plugin = MyPlugin)]
+fn explode(_trigger: Trigger<Explode>, mut explosions: ResMut<Explosions>) {
+    explosions.0 += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    app.world_mut().trigger(Explode);
+    assert_eq!(app.world().resource::<Explosions>().0, 1);
+
+    assert!(!app.world().contains_resource::<Events<Explode>>());
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[should_panic(expected = "Event not initialized")]
+fn reader() {
+    let mut app = App::new();
+    app.add_plugins(MyPlugin)
+        .add_systems(Update, |mut events: EventReader<Explode>| events.clear());
+    app.update();
+}
@@ -1,4 +1,7 @@
 include!("../common.rs");
 
 mod observer;
+mod observer_name;
 mod observer_use;
+mod on_component;
+mod on_spawn_of;
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[butler_plugin]
+struct OtherPlugin;
+
+#[derive(Event)]
+struct Message;
+
+#[add_observer(plugin = MyPlugin)]
+fn receive_message(_message: Trigger<Message>) {}
+
+#[add_observer(plugin = MyPlugin, name = "Custom observer name")]
+fn receive_message_renamed(_message: Trigger<Message>) {}
+
+#[add_observer(plugin = OtherPlugin)]
+fn other_receive_message(_message: Trigger<Message>) {}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .add_plugins((MyPlugin, OtherPlugin));
+
+    let world = app.world_mut();
+    let observers = ButlerObserver::<MyPlugin>::entities(world);
+    assert_eq!(observers.len(), 2);
+
+    let mut names: Vec<String> = observers
+        .iter()
+        .map(|e| world.get::<Name>(*e).unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "Custom observer name".to_string(),
+            format!("{}::receive_message", module_path!()),
+        ]
+    );
+
+    ButlerObserver::<MyPlugin>::despawn_all(world);
+    assert!(ButlerObserver::<MyPlugin>::entities(world).is_empty());
+    assert_eq!(ButlerObserver::<OtherPlugin>::entities(world).len(), 1);
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component, Default)]
+struct Health(u32);
+
+#[derive(Event)]
+struct Damage(u32);
+
+#[add_observer(plugin = MyPlugin, on_component = Player)]
+fn take_damage(damage: Trigger<Damage>, mut health: Query<&mut Health>) {
+    health.get_mut(damage.target()).unwrap().0 += damage.0;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let world = app.world_mut();
+    let player = world.spawn((Player, Health::default())).id();
+    let npc = world.spawn(Health::default()).id();
+    world.flush();
+
+    world.trigger_targets(Damage(5), [player, npc]);
+    assert_eq!(world.get::<Health>(player).unwrap().0, 5);
+    assert_eq!(world.get::<Health>(npc).unwrap().0, 0);
+
+    // Removing the component should also remove the observer
+    world.entity_mut(player).remove::<Player>();
+    world.flush();
+
+    world.trigger_targets(Damage(5), player);
+    assert_eq!(world.get::<Health>(player).unwrap().0, 5);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn despawn_all() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let world = app.world_mut();
+    let mut names: Vec<String> = ButlerObserver::<MyPlugin>::entities(world)
+        .into_iter()
+        .map(|e| world.get::<Name>(e).unwrap().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            format!("{}::take_damage (attach)", module_path!()),
+            format!("{}::take_damage (detach)", module_path!()),
+        ]
+    );
+
+    let player = world.spawn((Player, Health::default())).id();
+    world.flush();
+    assert_eq!(ButlerObserver::<MyPlugin>::entities(world).len(), 3);
+
+    // Despawning the observers of the plugin also stops new entities from getting them
+    ButlerObserver::<MyPlugin>::despawn_all(world);
+    let new_player = world.spawn((Player, Health::default())).id();
+    world.flush();
+    assert!(ButlerObserver::<MyPlugin>::entities(world).is_empty());
+
+    world.trigger_targets(Damage(5), [player, new_player]);
+    assert_eq!(world.get::<Health>(player).unwrap().0, 0);
+    assert_eq!(world.get::<Health>(new_player).unwrap().0, 0);
+}
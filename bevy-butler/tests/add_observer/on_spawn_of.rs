@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component)]
+struct Door;
+
+#[derive(Component, Default)]
+struct Opened(bool);
+
+#[derive(Event)]
+struct Interact;
+
+#[add_observer(plugin = MyPlugin, on_spawn_of = Door)]
+fn open_door(interact: Trigger<Interact>, mut doors: Query<&mut Opened>) {
+    doors.get_mut(interact.target()).unwrap().0 = true;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let world = app.world_mut();
+    let door = world.spawn((Door, Opened::default())).id();
+    world.flush();
+
+    // The observer stays attached after the marker is removed
+    world.entity_mut(door).remove::<Door>();
+    world.flush();
+
+    world.trigger_targets(Interact, door);
+    assert!(world.get::<Opened>(door).unwrap().0);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn reinsert() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let world = app.world_mut();
+    let door = world.spawn((Door, Opened::default())).id();
+    world.flush();
+    let observers = ButlerObserver::<MyPlugin>::entities(world).len();
+
+    // Inserting the marker again keeps the observer that is already attached
+    world.entity_mut(door).remove::<Door>();
+    world.flush();
+    world.entity_mut(door).insert(Door);
+    world.flush();
+    assert_eq!(ButlerObserver::<MyPlugin>::entities(world).len(), observers);
+
+    // Other doors still get their own
+    world.spawn((Door, Opened::default()));
+    world.flush();
+    assert_eq!(ButlerObserver::<MyPlugin>::entities(world).len(), observers + 1);
+}
+
+mod with_on_component {
+    use super::*;
+
+    #[butler_plugin]
+    struct OtherPlugin;
+
+    #[derive(Resource, Default)]
+    struct Knocks(u32);
+
+    #[add_observer(plugin = OtherPlugin, on_component = Door)]
+    fn knock(_interact: Trigger<Interact>, mut knocks: ResMut<Knocks>) {
+        knocks.0 += 1;
+    }
+
+    // Removing the marker only detaches the `on_component` observer of another plugin
+    #[wasm_bindgen_test(unsupported = test)]
+    fn test() {
+        let mut app = App::new();
+        app.add_plugins(log_plugin())
+            .init_resource::<Knocks>()
+            .add_plugins((MyPlugin, OtherPlugin));
+
+        let world = app.world_mut();
+        let door = world.spawn((Door, Opened::default())).id();
+        world.flush();
+
+        world.entity_mut(door).remove::<Door>();
+        world.flush();
+
+        world.trigger_targets(Interact, door);
+        assert!(world.get::<Opened>(door).unwrap().0);
+        assert_eq!(world.resource::<Knocks>().0, 0);
+    }
+}
@@ -3,7 +3,7 @@ use std::{any::type_name, fmt::Display};
 use bevy_app::prelude::*;
 use bevy_butler::*;
 use bevy_ecs::prelude::*;
-use bevy_log::info;
+use bevy::log::info;
 use wasm_bindgen_test::wasm_bindgen_test;
 
 include!("../common.rs");
@@ -27,6 +27,7 @@ impl Plugin for MyPlugin {
 #[add_system(generics = <&str, &str>, plugin = MyPlugin, schedule = Startup, before = test_sys::<u8, u8>)]
 #[add_system(generics = <u8,u8>, plugin = MyPlugin, schedule = Startup, after = test_sys::<&str, &str>)]
 #[add_system(generics = <bool,bool>, plugin = MyPlugin, schedule = Startup)]
+#[allow(clippy::extra_unused_type_parameters)]
 fn test_sys<T: 'static + Sync + Send + Display, R>(mut res: ResMut<GenericResource<T>>) {
     info!("{} = {}", type_name::<T>(), res.0);
     res.1 = true;
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[butler_plugin(instrument)]
+struct InstrumentedPlugin;
+
+#[butler_plugin]
+struct PartialPlugin;
+
+#[add_system(plugin = InstrumentedPlugin, schedule = Update)]
+fn move_player() {}
+
+#[add_system(plugin = InstrumentedPlugin, schedule = Update, after = move_player)]
+fn resolve_collisions() {}
+
+#[add_system(plugin = PartialPlugin, schedule = Update, instrument, run_if = || true)]
+fn render() {}
+
+#[add_system(plugin = PartialPlugin, schedule = Update)]
+fn play_audio() {}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .init_resource::<ButlerSystemTimings>()
+        .add_plugins((InstrumentedPlugin, PartialPlugin));
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let timings = app.world().resource::<ButlerSystemTimings>();
+    let move_player = timings
+        .get("add_system::instrument::move_player")
+        .expect("move_player was not instrumented");
+    assert_eq!(move_player.runs, 3);
+    assert!(move_player.plugin.ends_with("InstrumentedPlugin"));
+
+    let render = timings
+        .get("add_system::instrument::render")
+        .expect("render was not instrumented");
+    assert_eq!(render.runs, 3);
+    assert!(render.plugin.ends_with("PartialPlugin"));
+
+    assert_eq!(timings.iter().count(), 3);
+    assert!(timings.get("add_system::instrument::play_audio").is_none());
+}
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::{TimePlugin, TimeUpdateStrategy};
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use super::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+struct Counts {
+    frames: u32,
+    every: u32,
+    every_frames: u32,
+    fixed: u32,
+}
+
+#[derive(Resource)]
+struct Paused;
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn count_frames(mut counts: ResMut<Counts>) {
+    counts.frames += 1;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, every = Duration::from_millis(250), after = count_frames)]
+fn every_250ms(mut counts: ResMut<Counts>) {
+    counts.every += 1;
+}
+
+#[add_system(plugin = MyPlugin, schedule = Update, every_frames = 3, run_if = not(resource_exists::<Paused>))]
+fn every_3_frames(mut counts: ResMut<Counts>) {
+    counts.every_frames += 1;
+}
+
+#[add_system(plugin = MyPlugin, fixed, every_frames = 2)]
+fn every_2_fixed_steps(mut counts: ResMut<Counts>) {
+    counts.fixed += 1;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), TimePlugin, MyPlugin))
+        .init_resource::<Counts>()
+        .insert_resource(Time::<Fixed>::from_hz(10.0))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+
+    for _ in 0..12 {
+        app.update();
+    }
+
+    let counts = app.world().resource::<Counts>();
+    assert_eq!(counts.frames, 12);
+    // The first update has no delta, so 1100ms has passed
+    assert_eq!(counts.every, 4);
+    assert_eq!(counts.every_frames, 4);
+    // One fixed step per update after the first
+    assert_eq!(counts.fixed, 5);
+
+    app.insert_resource(Paused);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Counts>().every_frames, 4);
+}
@@ -2,7 +2,10 @@ include!("../common.rs");
 
 mod generic_pipe;
 mod generic_system;
+mod instrument;
+mod interval;
 mod pipe;
+mod static_name;
 mod system;
 mod system_expr_schedule;
 mod use_declaration;
@@ -23,6 +23,28 @@ fn system3(input: In<String>) {
     info!("Number: {}", *input);
 }
 
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Doubled(i32);
+
+fn store_doubled(input: In<i32>, mut doubled: ResMut<Doubled>) {
+    doubled.0 = *input;
+}
+
+// `pipe` is a transform, not a misspelling of `pipe_in`
+#[add_system(plugin = MyPlugin, schedule = Startup, pipe(store_doubled))]
+fn double(res: Res<StartNumber>) -> i32 {
+    res.0 * 2
+}
+
+#[test]
+fn pipe_transform() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+    app.update();
+    assert_eq!(app.world().resource::<Doubled>().0, 20);
+}
+
 #[test]
 fn test() {
     App::new()
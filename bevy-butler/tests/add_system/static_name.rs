@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn hello() {}
+
+// `bevy-butler-build` refers to entries by the name of their static, which it derives from the
+// source. The same name is checked in `bevy-butler-build/tests/generate.rs`.
+#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+#[test]
+fn static_name() {
+    let _ = &_butler_add_system_e8f43da6278dd5d1ddf8dae407fe9c583e624d4ba8a88e447bf97e8816e5227a;
+}
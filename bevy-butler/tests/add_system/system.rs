@@ -1,7 +1,7 @@
 use bevy_app::prelude::*;
 use bevy_butler::*;
 use bevy_ecs::prelude::*;
-use bevy_log::prelude::*;
+use bevy::log::prelude::*;
 use wasm_bindgen_test::wasm_bindgen_test;
 
 use super::common::log_plugin;
@@ -20,7 +20,7 @@ struct Marker<T>(T, bool);
 
 mod function {
     use bevy_ecs::prelude::*;
-    use bevy_log::prelude::*;
+    use bevy::log::prelude::*;
     use std::any::type_name;
     use std::fmt::Display;
     pub(super) fn test_sys<T: 'static + Sync + Send + Display>(mut res: ResMut<super::Marker<T>>) {
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+struct Steps(Vec<&'static str>);
+
+#[derive(Resource)]
+struct Paused;
+
+fn read_input(mut steps: ResMut<Steps>) {
+    steps.0.push("read_input");
+}
+
+fn move_player(mut steps: ResMut<Steps>) {
+    steps.0.push("move_player");
+}
+
+fn resolve_collisions(mut steps: ResMut<Steps>) {
+    steps.0.push("resolve_collisions");
+}
+
+mod nested {
+    use super::*;
+
+    pub(super) fn render(mut steps: ResMut<Steps>) {
+        steps.0.push("render");
+    }
+}
+
+butler_chain!(
+    plugin = MyPlugin,
+    schedule = Update,
+    [read_input, move_player, resolve_collisions]
+);
+
+butler_chain!(
+    [nested::render],
+    plugin = MyPlugin,
+    schedule = PostUpdate,
+    run_if = not(resource_exists::<Paused>),
+);
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .init_resource::<Steps>()
+        .add_plugins(MyPlugin);
+
+    app.update();
+    assert_eq!(
+        app.world().resource::<Steps>().0,
+        ["read_input", "move_player", "resolve_collisions", "render"]
+    );
+
+    app.insert_resource(Paused);
+    app.world_mut().resource_mut::<Steps>().0.clear();
+    app.update();
+    assert_eq!(
+        app.world().resource::<Steps>().0,
+        ["read_input", "move_player", "resolve_collisions"]
+    );
+}
@@ -0,0 +1,3 @@
+include!("../common.rs");
+
+mod chain;
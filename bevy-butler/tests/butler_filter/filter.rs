@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[butler_plugin]
+#[add_plugin(to_plugin = GamePlugin)]
+struct ServerPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = GamePlugin)]
+struct Score(u32);
+
+#[add_system(plugin = GamePlugin, schedule = Update)]
+fn add_score(mut score: ResMut<Score>) {
+    score.0 += 1;
+}
+
+mod netcode {
+    use bevy::prelude::*;
+    use bevy_butler::*;
+
+    #[derive(Resource, Default)]
+    #[insert_resource(plugin = super::GamePlugin)]
+    pub(super) struct Connection;
+
+    #[add_system(plugin = super::ServerPlugin, schedule = Update)]
+    fn send_score(mut score: ResMut<super::Score>) {
+        score.0 += 100;
+    }
+}
+
+fn app(filter: Option<ButlerFilter>) -> App {
+    let mut app = App::new();
+    app.add_plugins(log_plugin());
+    if let Some(filter) = filter {
+        app.insert_resource(filter);
+    }
+    app.add_plugins(GamePlugin);
+    app.update();
+    app
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn unfiltered() {
+    let app = app(None);
+    assert_eq!(app.world().resource::<Score>().0, 101);
+    assert!(app.world().contains_resource::<netcode::Connection>());
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn deny_module() {
+    let app = app(Some(ButlerFilter::deny(["netcode::*"])));
+    assert_eq!(app.world().resource::<Score>().0, 1);
+    assert!(!app.world().contains_resource::<netcode::Connection>());
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn deny_nested_plugin() {
+    let app = app(Some(ButlerFilter::deny(["ServerPlugin"])));
+    assert!(!app.is_plugin_added::<ServerPlugin>());
+    assert_eq!(app.world().resource::<Score>().0, 1);
+    assert!(app.world().contains_resource::<netcode::Connection>());
+}
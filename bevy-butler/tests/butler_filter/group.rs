@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin_group]
+struct GamePlugins;
+
+#[butler_plugin]
+#[add_plugin(to_group = GamePlugins)]
+struct PhysicsPlugin;
+
+mod debug {
+    use bevy_butler::*;
+
+    #[butler_plugin]
+    #[add_plugin(to_group = super::GamePlugins)]
+    pub(super) struct OverlayPlugin;
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn unfiltered() {
+    let mut app = App::new();
+    app.add_plugins(ButlerFilter::default().build_group(GamePlugins));
+    assert!(app.is_plugin_added::<PhysicsPlugin>());
+    assert!(app.is_plugin_added::<debug::OverlayPlugin>());
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn deny_module() {
+    let mut app = App::new();
+    app.add_plugins(ButlerFilter::deny(["debug::*"]).build_group(GamePlugins));
+    assert!(app.is_plugin_added::<PhysicsPlugin>());
+    assert!(!app.is_plugin_added::<debug::OverlayPlugin>());
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn allow_plugin() {
+    let mut app = App::new();
+    app.add_plugins(ButlerFilter::allow(["PhysicsPlugin"]).build_group(GamePlugins));
+    assert!(app.is_plugin_added::<PhysicsPlugin>());
+    assert!(!app.is_plugin_added::<debug::OverlayPlugin>());
+}
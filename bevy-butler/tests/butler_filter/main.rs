@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod filter;
+mod group;
+mod patterns;
@@ -0,0 +1,34 @@
+use bevy_butler::ButlerFilter;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn deny() {
+    let filter = ButlerFilter::deny(["netcode::*", "*::debug_overlay"]);
+    assert!(!filter.is_allowed("game::netcode::sync"));
+    assert!(!filter.is_allowed("game::server::netcode::send::<u32>"));
+    assert!(!filter.is_allowed("game::ui::debug_overlay"));
+    assert!(filter.is_allowed("game::netcode_stats::record"));
+    assert!(filter.is_allowed("game::ui::debug_overlay_toggle"));
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn allow() {
+    let filter = ButlerFilter::allow(["game::physics::*"]).and_deny(["*::Gravity"]);
+    assert!(filter.is_allowed("game::physics::step"));
+    assert!(!filter.is_allowed("game::physics::Gravity"));
+    assert!(!filter.is_allowed("game::audio::play"));
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn many_wildcards() {
+    // Would take exponential time if each `*` was retried for every split of the identifier
+    let pattern = "*a".repeat(30) + "b";
+    let filter = ButlerFilter::deny([pattern]);
+    assert!(filter.is_allowed(&"a".repeat(60)));
+    assert!(!filter.is_allowed(&("a".repeat(60) + "b")));
+
+    let filter = ButlerFilter::deny(["game::*::*::sync*"]);
+    assert!(!filter.is_allowed("game::server::netcode::sync_positions"));
+    assert!(!filter.is_allowed("game::a::b::c::sync"));
+    assert!(filter.is_allowed("game::server::sync"));
+}
@@ -4,3 +4,4 @@ mod butler_plugin;
 mod butler_plugin_enum;
 mod butler_plugin_impl;
 mod multiple_plugins;
+mod plugin_paths;
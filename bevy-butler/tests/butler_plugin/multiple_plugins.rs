@@ -1,6 +1,6 @@
 use bevy_app::{App, Startup};
 use bevy_butler::*;
-use bevy_log::info;
+use bevy::log::info;
 
 use crate::common::log_plugin;
 use wasm_bindgen_test::wasm_bindgen_test;
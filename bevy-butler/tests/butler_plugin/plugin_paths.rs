@@ -0,0 +1,115 @@
+use bevy_app::{App, Plugin, Plugins, Startup};
+use bevy_butler::*;
+use bevy_ecs::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Resource, Default)]
+struct Ran(Vec<&'static str>);
+
+mod game {
+    use bevy_butler::*;
+
+    #[butler_plugin]
+    pub struct GamePlugin;
+
+    pub mod nested {
+        use bevy_app::Startup;
+        use bevy_butler::*;
+        use bevy_ecs::prelude::*;
+
+        // Same name as `game::GamePlugin`, but a separate plugin
+        #[butler_plugin]
+        pub struct GamePlugin;
+
+        #[add_system(plugin = GamePlugin, schedule = Startup)]
+        fn nested_system(mut ran: ResMut<crate::plugin_paths::Ran>) {
+            ran.0.push("nested");
+        }
+    }
+
+    pub use nested::GamePlugin as NestedPlugin;
+}
+
+use game::GamePlugin as Game;
+
+type GameAlias = game::GamePlugin;
+
+mod other {
+    pub struct OtherPlugin;
+}
+
+// Annotated outside of the module declaring the plugin
+#[butler_plugin]
+impl Plugin for other::OtherPlugin {}
+
+#[add_system(plugin = game::GamePlugin, schedule = Startup)]
+fn by_path(mut ran: ResMut<Ran>) {
+    ran.0.push("path");
+}
+
+#[add_system(plugin = Game, schedule = Startup)]
+fn by_alias(mut ran: ResMut<Ran>) {
+    ran.0.push("alias");
+}
+
+#[add_system(plugin = GameAlias, schedule = Startup)]
+fn by_type_alias(mut ran: ResMut<Ran>) {
+    ran.0.push("type_alias");
+}
+
+#[add_system(plugin = other::OtherPlugin, schedule = Startup)]
+fn other_module(mut ran: ResMut<Ran>) {
+    ran.0.push("other");
+}
+
+#[add_system(plugin = game::NestedPlugin, schedule = Startup)]
+fn by_reexport(mut ran: ResMut<Ran>) {
+    ran.0.push("reexport");
+}
+
+fn run<M>(plugin: impl Plugins<M>) -> Vec<&'static str> {
+    let mut app = App::new();
+    app.init_resource::<Ran>().add_plugins(plugin);
+    app.update();
+    let mut ran = app.world_mut().remove_resource::<Ran>().unwrap().0;
+    ran.sort();
+    ran
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    assert_eq!(run(game::GamePlugin), ["alias", "path", "type_alias"]);
+    assert_eq!(run(game::nested::GamePlugin), ["nested", "reexport"]);
+    assert_eq!(run(other::OtherPlugin), ["other"]);
+}
+
+fn first_body() -> Vec<&'static str> {
+    #[butler_plugin]
+    struct BodyPlugin;
+
+    #[add_system(plugin = BodyPlugin, schedule = Startup)]
+    fn first(mut ran: ResMut<Ran>) {
+        ran.0.push("first");
+    }
+
+    run(BodyPlugin)
+}
+
+fn second_body() -> Vec<&'static str> {
+    #[butler_plugin]
+    struct BodyPlugin;
+
+    #[add_system(plugin = BodyPlugin, schedule = Startup)]
+    fn second(mut ran: ResMut<Ran>) {
+        ran.0.push("second");
+    }
+
+    run(BodyPlugin)
+}
+
+// Plugins with the same path in separate function bodies are still separate plugins
+#[wasm_bindgen_test(unsupported = test)]
+fn function_bodies() {
+    assert_eq!(first_body(), ["first"]);
+    assert_eq!(second_body(), ["second"]);
+}
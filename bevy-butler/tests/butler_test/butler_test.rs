@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Counter(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[butler_test(plugins = [log_plugin(), MyPlugin], updates = 5)]
+fn updates(world: &mut World) {
+    assert_eq!(world.resource::<Counter>().0, 5);
+}
+
+#[butler_test(plugins = [MyPlugin], updates = 0)]
+fn no_updates(world: &mut World) {
+    assert_eq!(world.resource::<Counter>().0, 0);
+}
+
+#[butler_test(plugins = [MyPlugin], updates = 1)]
+#[should_panic(expected = "assertion `left == right` failed")]
+fn forwards_panics(world: &mut World) {
+    assert_eq!(world.resource::<Counter>().0, 2);
+}
+
+// Tests only time out with `std`, which runs them on their own thread
+#[cfg(feature = "std")]
+#[butler_test(updates = 1, timeout = std::time::Duration::from_millis(100))]
+#[should_panic(expected = "Test timed out after 100ms")]
+fn times_out() {
+    std::thread::sleep(std::time::Duration::from_secs(1));
+}
@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod butler_test;
+mod until_exit;
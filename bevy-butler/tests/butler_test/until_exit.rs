@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[insert_state(plugin = MyPlugin)]
+enum MyState {
+    #[default]
+    Start,
+    End,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Frames(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn count_frames(mut frames: ResMut<Frames>, mut next_state: ResMut<NextState<MyState>>) {
+    frames.0 += 1;
+    if frames.0 == 3 {
+        next_state.set(MyState::End);
+    }
+}
+
+#[add_system(plugin = MyPlugin, schedule = OnEnter(MyState::End))]
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.write(AppExit::Success);
+}
+
+#[butler_test(plugins = [StatesPlugin, MyPlugin])]
+fn runs_until_exit(world: &mut World) {
+    // The state changes at the start of the frame after it was set
+    assert_eq!(world.resource::<Frames>().0, 4);
+    assert_eq!(*world.resource::<State<MyState>>(), MyState::End);
+}
@@ -1,5 +1,17 @@
 pub mod common {
-    use bevy_log::{Level, LogPlugin};
+    use std::{
+        fmt::{Debug, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use bevy::log::{
+        tracing::{
+            field::{Field, Visit},
+            subscriber, Event, Subscriber,
+        },
+        tracing_subscriber::{layer::Context, prelude::*, registry, Layer},
+        Level, LogPlugin,
+    };
 
     #[allow(dead_code)]
     pub fn log_plugin() -> LogPlugin {
@@ -9,4 +21,37 @@ pub mod common {
             ..Default::default()
         }
     }
+
+    /// Runs `f` and returns every log line it emitted on this thread, formatted as
+    /// `"LEVEL message field=value ..."`.
+    #[allow(dead_code)]
+    pub fn capture_logs(f: impl FnOnce()) -> Vec<String> {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = registry().with(CaptureLayer(logs.clone()));
+        subscriber::with_default(subscriber, f);
+        Arc::try_unwrap(logs).unwrap().into_inner().unwrap()
+    }
+
+    struct CaptureLayer(Arc<Mutex<Vec<String>>>);
+
+    impl<S: Subscriber> Layer<S> for CaptureLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let mut line = LogLine(event.metadata().level().to_string());
+            event.record(&mut line);
+            self.0.lock().unwrap().push(line.0);
+        }
+    }
+
+    struct LogLine(String);
+
+    impl Visit for LogLine {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            match field.name() {
+                "message" => write!(self.0, " {value:?}").unwrap(),
+                // Metadata of records forwarded from the `log` crate, like in `no_std` builds
+                name if name.starts_with("log.") => {}
+                name => write!(self.0, " {name}={value:?}").unwrap(),
+            }
+        }
+    }
 }
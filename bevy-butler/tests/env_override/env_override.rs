@@ -0,0 +1,224 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::{capture_logs, log_plugin};
+
+const VARS: &[(&str, &str)] = &[
+    ("BUTLER_TEST_PHYSICS_GRAVITY", "12"),
+    ("BUTLER_TEST_PHYSICS_MAX_SPEED", "25"),
+    ("BUTLER_TEST_AUDIO_VOLUEM", "0.5"),
+    ("BUTLER_TEST_AUDIO_MIXER_CHANNELS", "8"),
+    ("BUTLER_TEST_NETWORK_PORT", "http"),
+    ("BUTLER_TEST_INPUT_SENSITIVTY", "2"),
+    ("BUTLER_TEST_VIDEO_WIDTH", "1920"),
+    ("BUTLER_TEST_WINDOW_TITLE", "Butler"),
+    ("BUTLER_TEST_WINDOW_SIZE", "[1280, 720]"),
+];
+
+pub fn set_vars() {
+    for (key, value) in VARS {
+        // SAFETY: No other threads have been started yet
+        #[allow(unused_unsafe)]
+        unsafe { std::env::set_var(key, value) };
+    }
+
+    // Variables that aren't valid UTF-8 are skipped, instead of panicking
+    #[cfg(unix)]
+    {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+        #[allow(unused_unsafe)]
+        unsafe {
+            std::env::set_var(OsStr::from_bytes(b"BUTLER_TEST_\xff"), "1");
+            std::env::set_var("BUTLER_TEST_PHYSICS_UNRELATED", OsStr::from_bytes(b"\xff"));
+        }
+    }
+}
+
+pub fn run() {
+    case("reflect", reflect::test);
+    case("overlapping_prefix", overlapping_prefix::test);
+    case("invalid_value", invalid_value::test);
+    case("unknown_field", unknown_field::test);
+    case("skipped_init", skipped_init::test);
+    #[cfg(feature = "json")]
+    case("serde", serde::test);
+}
+
+fn case(name: &str, test: fn()) {
+    test();
+    println!("test env_override::{name} ... ok");
+}
+
+/// Runs `f`, which is expected to panic, and returns the panic message.
+fn expect_panic(f: impl FnOnce()) -> String {
+    // Keep the expected panic out of the test output
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let error = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    std::panic::set_hook(hook);
+    *error.downcast::<String>().unwrap()
+}
+
+mod reflect {
+    use super::*;
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource, Reflect)]
+    #[insert_resource(
+        plugin = MyPlugin,
+        init = Physics { gravity: 9.8, max_speed: 10, paused: false },
+        env_prefix = "BUTLER_TEST_PHYSICS",
+    )]
+    struct Physics {
+        gravity: f32,
+        max_speed: u32,
+        paused: bool,
+    }
+
+    pub fn test() {
+        let mut app = App::new();
+        app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+        let physics = app.world().resource::<Physics>();
+        assert_eq!(physics.gravity, 12.0);
+        assert_eq!(physics.max_speed, 25);
+        assert!(!physics.paused);
+    }
+}
+
+mod overlapping_prefix {
+    use super::*;
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource, Reflect, Default)]
+    #[insert_resource(plugin = MyPlugin, env_prefix = "BUTLER_TEST_AUDIO", env_ignore_unknown)]
+    struct Audio {
+        volume: f32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[insert_resource(plugin = MyPlugin, env_prefix = "BUTLER_TEST_AUDIO_MIXER")]
+    struct Mixer {
+        channels: u8,
+    }
+
+    pub fn test() {
+        let mut app = App::new();
+        let logs = capture_logs(|| {
+            app.add_plugins(MyPlugin);
+        });
+
+        // `Audio` skips the misspelled field and `Mixer`'s field, but `Mixer` still gets it
+        let skipped: Vec<_> = logs.iter().filter(|log| log.contains("Skipping")).collect();
+        assert_eq!(skipped.len(), 2, "{logs:?}");
+        assert!(skipped.iter().any(|log| log.contains("BUTLER_TEST_AUDIO_VOLUEM")));
+        assert!(skipped.iter().any(|log| log.contains("BUTLER_TEST_AUDIO_MIXER_CHANNELS")));
+        assert_eq!(app.world().resource::<Audio>().volume, 0.0);
+        assert_eq!(app.world().resource::<Mixer>().channels, 8);
+    }
+}
+
+mod invalid_value {
+    use super::*;
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource, Reflect, Default)]
+    #[insert_resource(plugin = MyPlugin, env_prefix = "BUTLER_TEST_NETWORK")]
+    struct Network {
+        port: u16,
+    }
+
+    pub fn test() {
+        let message = expect_panic(|| {
+            App::new().add_plugins(MyPlugin);
+        });
+        assert!(
+            message.contains("Failed to apply BUTLER_TEST_NETWORK_PORT=http"),
+            "{message}"
+        );
+    }
+}
+
+mod unknown_field {
+    use super::*;
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource, Reflect, Default)]
+    #[insert_resource(plugin = MyPlugin, env_prefix = "BUTLER_TEST_INPUT")]
+    struct Input {
+        sensitivity: f32,
+        invert_y: bool,
+    }
+
+    pub fn test() {
+        let message = expect_panic(|| {
+            App::new().add_plugins(MyPlugin);
+        });
+        assert!(
+            message.contains("BUTLER_TEST_INPUT_SENSITIVTY does not match any field"),
+            "{message}"
+        );
+        assert!(message.contains("Expected one of: sensitivity, invert_y"), "{message}");
+    }
+}
+
+mod skipped_init {
+    use super::*;
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource, Reflect)]
+    #[insert_resource(
+        plugin = MyPlugin,
+        init_with = |_| Err::<Video, _>("no display"),
+        on_init_error = skip,
+        env_prefix = "BUTLER_TEST_VIDEO",
+    )]
+    struct Video {
+        width: u32,
+    }
+
+    pub fn test() {
+        let mut app = App::new();
+        app.add_plugins(MyPlugin);
+        assert!(!app.world().contains_resource::<Video>());
+    }
+}
+
+#[cfg(feature = "json")]
+mod serde {
+    use super::*;
+    use ::serde::{Deserialize, Serialize};
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource, Serialize, Deserialize, Default)]
+    #[insert_resource(plugin = MyPlugin, env_prefix = "BUTLER_TEST_WINDOW")]
+    struct Window {
+        title: String,
+        size: (u32, u32),
+        vsync: bool,
+    }
+
+    pub fn test() {
+        let mut app = App::new();
+        app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+        let window = app.world().resource::<Window>();
+        assert_eq!(window.title, "Butler");
+        assert_eq!(window.size, (1280, 720));
+        assert!(!window.vsync);
+    }
+}
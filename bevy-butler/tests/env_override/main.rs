@@ -0,0 +1,12 @@
+//! Overrides are read from the process environment, which can't be written safely while other
+//! threads are running. This binary runs without the test harness, so it sets every variable
+//! up front and then runs each case in order on the main thread.
+
+include!("../common.rs");
+
+mod env_override;
+
+fn main() {
+    env_override::set_vars();
+    env_override::run();
+}
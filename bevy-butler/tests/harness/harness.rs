@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[derive(Resource, Default)]
+struct Built;
+
+#[butler_plugin]
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Built);
+    }
+}
+struct GamePlugin;
+
+#[derive(Resource)]
+struct Speed(u32);
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = GamePlugin)]
+struct Position(u32);
+
+#[add_system(plugin = GamePlugin, schedule = Update)]
+fn movement(speed: Res<Speed>, mut position: ResMut<Position>) {
+    position.0 += speed.0;
+}
+
+mod physics {
+    use bevy::prelude::*;
+    use bevy_butler::*;
+
+    #[derive(Resource)]
+    pub(super) struct Gravity;
+
+    #[add_system(plugin = super::GamePlugin, schedule = Update)]
+    fn apply_gravity(_gravity: Res<Gravity>) {}
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn only_systems() {
+    let mut app = ButlerPluginHarness::<GamePlugin>::new()
+        .with_plugins(log_plugin())
+        .only_systems(["movement"])
+        .with_resource(Speed(2))
+        .with_resource(Position(1))
+        .build();
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<Position>().0, 5);
+    assert!(!app.world().contains_resource::<Built>());
+    assert!(!app.world().contains_resource::<ButlerFilter>());
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn skip() {
+    let mut app = ButlerPluginHarness::<GamePlugin>::new()
+        .with_plugins(log_plugin())
+        .skip(["physics::*"])
+        .with_resource(Speed(3))
+        .build();
+    app.update();
+
+    assert_eq!(app.world().resource::<Position>().0, 3);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn keeps_existing_filter() {
+    let app = ButlerPluginHarness::<GamePlugin>::new()
+        .with_plugins(log_plugin())
+        .with_resource(ButlerFilter::deny(["Position"]))
+        .only_systems(["Position"])
+        .build();
+
+    assert!(app.world().contains_resource::<Position>());
+    assert!(app.world().contains_resource::<ButlerFilter>());
+}
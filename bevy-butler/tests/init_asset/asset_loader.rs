@@ -0,0 +1,81 @@
+use core::any::type_name;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use bevy::tasks::block_on;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Asset, TypePath)]
+#[init_asset(plugin = MyPlugin)]
+struct LevelData(#[allow(dead_code)] Vec<u8>);
+
+#[derive(Default)]
+#[register_asset_loader(plugin = MyPlugin)]
+struct LevelLoader;
+
+#[register_asset_loader(plugin = MyPlugin, init = CompressedLevelLoader { level: 9 })]
+struct CompressedLevelLoader {
+    level: u8,
+}
+
+async fn read_level(reader: &mut dyn Reader) -> Result<LevelData, std::io::Error> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+    Ok(LevelData(bytes))
+}
+
+impl AssetLoader for LevelLoader {
+    type Asset = LevelData;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<LevelData, Self::Error> {
+        read_level(reader).await
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level"]
+    }
+}
+
+impl AssetLoader for CompressedLevelLoader {
+    type Asset = LevelData;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<LevelData, Self::Error> {
+        assert_eq!(self.level, 9);
+        read_level(reader).await
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["zlevel"]
+    }
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), TaskPoolPlugin::default(), AssetPlugin::default()))
+        .add_plugins(MyPlugin);
+
+    let server = app.world().resource::<AssetServer>();
+    assert!(block_on(server.get_asset_loader_with_type_name(type_name::<LevelLoader>())).is_ok());
+    assert!(block_on(server.get_asset_loader_with_extension("level")).is_ok());
+    assert!(block_on(server.get_asset_loader_with_extension("zlevel")).is_ok());
+}
@@ -0,0 +1,120 @@
+use core::any::type_name;
+
+use bevy::asset::{
+    io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetSourceBuilder, AssetSourceBuilders, AssetSourceId, Reader, Writer,
+    },
+    meta::AssetMeta,
+    processor::{AssetProcessor, Process, ProcessContext, ProcessError},
+    AssetLoader, AsyncWriteExt, LoadContext,
+};
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Asset, TypePath)]
+struct LevelData(#[allow(dead_code)] Vec<u8>);
+
+#[derive(Default)]
+struct LevelLoader;
+
+impl AssetLoader for LevelLoader {
+    type Asset = LevelData;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<LevelData, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(LevelData(bytes))
+    }
+}
+
+#[derive(Default)]
+#[register_asset_processor(plugin = MyPlugin, default_extension = "level")]
+struct LevelProcessor;
+
+#[register_asset_processor(plugin = MyPlugin, init = StrippingProcessor { strip: 1 })]
+struct StrippingProcessor {
+    strip: usize,
+}
+
+async fn copy_level(
+    context: &mut ProcessContext<'_>,
+    writer: &mut Writer,
+    strip: usize,
+) -> Result<(), ProcessError> {
+    let bytes = context.asset_bytes().get(strip..).unwrap_or_default();
+    writer
+        .write_all(bytes)
+        .await
+        .map_err(|e| ProcessError::AssetWriterError {
+            path: context.path().clone_owned(),
+            err: e.into(),
+        })
+}
+
+impl Process for LevelProcessor {
+    type Settings = ();
+    type OutputLoader = LevelLoader;
+
+    async fn process(
+        &self,
+        context: &mut ProcessContext<'_>,
+        _meta: AssetMeta<(), Self>,
+        writer: &mut Writer,
+    ) -> Result<(), ProcessError> {
+        copy_level(context, writer, 0).await
+    }
+}
+
+impl Process for StrippingProcessor {
+    type Settings = ();
+    type OutputLoader = LevelLoader;
+
+    async fn process(
+        &self,
+        context: &mut ProcessContext<'_>,
+        _meta: AssetMeta<(), Self>,
+        writer: &mut Writer,
+    ) -> Result<(), ProcessError> {
+        copy_level(context, writer, self.strip).await
+    }
+}
+
+/// An asset processor over an in-memory source, so that the test doesn't touch the file system.
+fn asset_processor() -> AssetProcessor {
+    let mut sources = AssetSourceBuilders::default();
+    sources.insert(
+        AssetSourceId::Default,
+        AssetSourceBuilder::default()
+            .with_reader(|| Box::new(MemoryAssetReader { root: Dir::default() }))
+            .with_processed_reader(|| Box::new(MemoryAssetReader { root: Dir::default() })),
+    );
+    AssetProcessor::new(&mut sources)
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .insert_resource(asset_processor())
+        .add_plugins(MyPlugin);
+
+    let processor = app.world().resource::<AssetProcessor>();
+    assert!(processor.get_processor(type_name::<LevelProcessor>()).is_some());
+    assert!(processor
+        .get_processor(type_name::<StrippingProcessor>())
+        .is_some());
+    assert!(processor.get_default_processor("level").is_some());
+}
@@ -0,0 +1,31 @@
+use bevy::asset::ReflectAsset;
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Asset, Reflect)]
+#[init_asset(plugin = MyPlugin)]
+#[register_asset_reflect(plugin = MyPlugin)]
+struct LevelData {
+    tiles: Vec<u8>,
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), TaskPoolPlugin::default(), AssetPlugin::default()))
+        .add_plugins(MyPlugin);
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let registration = registry
+        .get_with_short_type_path("LevelData")
+        .expect("LevelData was not registered to the type registry");
+    assert!(registration.data::<ReflectAsset>().is_some());
+    assert!(registry
+        .get_with_short_type_path("Handle<LevelData>")
+        .is_some());
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Asset, TypePath)]
+#[init_asset(plugin = MyPlugin, generics = <u8>)]
+#[init_asset(plugin = MyPlugin, generics = <String>)]
+struct Table<T: Send + Sync + TypePath>(#[allow(dead_code)] Vec<T>);
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), TaskPoolPlugin::default(), AssetPlugin::default()))
+        .add_plugins(MyPlugin);
+
+    assert!(app.world().contains_resource::<Assets<Table<u8>>>());
+    assert!(app.world().contains_resource::<Assets<Table<String>>>());
+    assert!(!app.world().contains_resource::<Assets<Table<u16>>>());
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Asset, TypePath)]
+#[init_asset(plugin = MyPlugin)]
+struct LevelData(Vec<u8>);
+
+mod imported {
+    use bevy::prelude::*;
+
+    #[derive(Asset, TypePath)]
+    pub struct Tileset;
+}
+
+#[init_asset(plugin = MyPlugin)]
+use imported::Tileset;
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins((log_plugin(), TaskPoolPlugin::default(), AssetPlugin::default()))
+        .add_plugins(MyPlugin);
+
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<LevelData>>()
+        .add(LevelData(vec![1, 2, 3]));
+    assert!(app.world().contains_resource::<Events<AssetEvent<LevelData>>>());
+    assert!(app.world().contains_resource::<Assets<Tileset>>());
+
+    app.update();
+    assert_eq!(
+        app.world().resource::<Assets<LevelData>>().get(&handle).unwrap().0,
+        [1, 2, 3]
+    );
+}
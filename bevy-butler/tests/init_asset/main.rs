@@ -0,0 +1,7 @@
+include!("../common.rs");
+
+mod asset_loader;
+mod asset_processor;
+mod asset_reflect;
+mod generic_asset;
+mod init_asset;
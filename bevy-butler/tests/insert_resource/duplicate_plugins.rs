@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::{capture_logs, log_plugin};
+
+#[butler_plugin]
+struct ClientPlugin;
+
+#[butler_plugin]
+struct ServerPlugin;
+
+// Registered by two plugins, which warns in debug builds
+#[derive(Resource)]
+#[insert_resource(plugin = ClientPlugin, init = TickRate(30))]
+#[insert_resource(plugin = ServerPlugin, init = TickRate(60))]
+struct TickRate(u32);
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin());
+    let logs = capture_logs(|| {
+        app.add_plugins(ClientPlugin).add_plugins(ServerPlugin);
+    });
+
+    assert_eq!(app.world().resource::<TickRate>().0, 60);
+
+    let warnings: Vec<_> = logs.iter().filter(|log| log.starts_with("WARN")).collect();
+    if cfg!(debug_assertions) {
+        let [warning] = warnings.as_slice() else {
+            panic!("{logs:?}");
+        };
+        assert!(warning.contains("TickRate is registered by both"), "{warning}");
+        assert!(warning.contains("ClientPlugin and"), "{warning}");
+        assert!(
+            warning.ends_with("ServerPlugin. Only one of them should register it."),
+            "{warning}"
+        );
+    } else {
+        assert!(warnings.is_empty(), "{logs:?}");
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource)]
+struct Seed(u64);
+
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, init_with = Rng::from_world_seed)]
+struct Rng(u64);
+
+impl Rng {
+    fn from_world_seed(world: &mut World) -> Self {
+        Rng(world.get_resource::<Seed>().map_or(0, |seed| seed.0) * 2)
+    }
+}
+
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, init_with = load_address)]
+struct ServerAddress(std::net::SocketAddr);
+
+fn load_address(_world: &mut World) -> Result<ServerAddress, std::net::AddrParseError> {
+    "127.0.0.1:5000".parse().map(ServerAddress)
+}
+
+#[derive(Resource)]
+#[insert_resource(plugin = MyPlugin, init_with = |_| "not a number".parse().map(Port), on_init_error = skip)]
+struct Port(u16);
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .insert_resource(Seed(21))
+        .add_plugins(MyPlugin);
+
+    let world = app.world();
+    assert_eq!(world.resource::<Rng>().0, 42);
+    assert_eq!(world.resource::<ServerAddress>().0.port(), 5000);
+    assert_eq!(world.get_resource::<Port>().map(|port| port.0), None);
+}
+
+mod init_error {
+    use bevy::prelude::*;
+    use bevy_butler::*;
+
+    #[butler_plugin]
+    struct MyPlugin;
+
+    #[derive(Resource)]
+    #[insert_resource(plugin = MyPlugin, init_with = |_| Err::<SaveData, _>("no save data"))]
+    struct SaveData;
+
+    #[test]
+    #[should_panic(
+        expected = "Failed to initialize insert_resource::init_with::init_error::SaveData for insert_resource::init_with::init_error::MyPlugin: no save data"
+    )]
+    fn test() {
+        App::new().add_plugins(MyPlugin);
+    }
+}
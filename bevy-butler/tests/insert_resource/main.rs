@@ -1,6 +1,11 @@
 include!("../common.rs");
 
+mod duplicate_plugins;
 mod generic_resource;
+mod init_with;
 mod non_send;
+mod reflect_resource;
 mod resource;
 mod resource_enum;
+mod skipped_init;
+mod trace_changes;
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use bevy_butler::*;
-use bevy_log::info;
+use bevy::log::info;
 use wasm_bindgen_test::wasm_bindgen_test;
 
 use crate::common::log_plugin;
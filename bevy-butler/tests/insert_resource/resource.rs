@@ -1,7 +1,7 @@
 use bevy::prelude::{Res, ResMut, Resource};
 use bevy_app::{App, PostStartup, Startup};
 use bevy_butler::*;
-use bevy_log::info;
+use bevy::log::info;
 use wasm_bindgen_test::wasm_bindgen_test;
 
 use crate::common::log_plugin;
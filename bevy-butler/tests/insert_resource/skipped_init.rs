@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Reflect, Debug)]
+#[insert_resource(
+    plugin = MyPlugin,
+    init_with = |_| Err::<Controller, _>("no controller connected"),
+    on_init_error = skip,
+    trace_changes,
+    reflect,
+)]
+struct Controller {
+    id: u32,
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+    assert!(!app.world().contains_resource::<Controller>());
+
+    // Tracing the missing resource does nothing, until it's inserted later
+    app.update();
+    app.insert_resource(Controller { id: 1 });
+    app.update();
+    app.world_mut().resource_mut::<Controller>().id = 2;
+    app.update();
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry
+        .get_type_data::<ReflectResource>(core::any::TypeId::of::<Controller>())
+        .is_some());
+}
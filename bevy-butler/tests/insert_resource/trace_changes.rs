@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::{capture_logs, log_plugin};
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Reflect, Default)]
+struct Audio {
+    volume: f32,
+    muted: bool,
+}
+
+#[derive(Resource, Reflect, Default)]
+#[insert_resource(plugin = MyPlugin, reflect, trace_changes)]
+struct Settings {
+    audio: Audio,
+    name: String,
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin, trace_changes)]
+struct Counter(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn mutate(mut settings: ResMut<Settings>, mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+    match counter.0 {
+        2 => {
+            settings.audio.volume = 0.5;
+            settings.name = "Player".to_string();
+        }
+        // Marks the resource as changed without changing any field
+        3 => settings.audio.muted = false,
+        _ => {}
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let logs: Vec<Vec<String>> = (0..3)
+        .map(|_| {
+            capture_logs(|| app.update())
+                .into_iter()
+                .filter(|log| log.contains("Settings changed"))
+                .collect()
+        })
+        .collect();
+
+    // Changes are only traced in debug builds
+    if cfg!(debug_assertions) {
+        // Inserting the resource isn't logged
+        assert!(logs[0].is_empty(), "{logs:?}");
+
+        // Nested fields are listed by their path, and unchanged fields are left out
+        let [changed] = logs[1].as_slice() else {
+            panic!("{logs:?}");
+        };
+        assert!(changed.contains("audio.volume: 0.0 -> 0.5"), "{changed}");
+        assert!(changed.contains(r#"name: "" -> "Player""#), "{changed}");
+        assert!(!changed.contains("muted"), "{changed}");
+
+        // A change that leaves every field the same is logged without a diff
+        let [unchanged] = logs[2].as_slice() else {
+            panic!("{logs:?}");
+        };
+        assert!(!unchanged.contains("->"), "{unchanged}");
+    }
+
+    let world = app.world();
+    assert_eq!(world.resource::<Counter>().0, 3);
+    assert_eq!(world.resource::<Settings>().audio.volume, 0.5);
+    assert_eq!(world.resource::<Settings>().name, "Player");
+}
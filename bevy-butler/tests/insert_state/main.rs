@@ -1,4 +1,5 @@
 include!("../common.rs");
 
 mod insert_state;
+mod multiple_states;
 mod with_init;
\ No newline at end of file
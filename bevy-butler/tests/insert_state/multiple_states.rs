@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use bevy_state::app::StatesPlugin;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[insert_state(plugin = GamePlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+enum GameState {
+    #[default]
+    Loading,
+}
+
+#[insert_state(plugin = GamePlugin)]
+#[derive(States, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+enum MenuState {
+    #[default]
+    Closed,
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+
+    app.add_plugins((StatesPlugin, GamePlugin));
+
+    let world = app.world();
+    assert_eq!(*world.resource::<State<GameState>>(), GameState::Loading);
+    assert_eq!(*world.resource::<State<MenuState>>(), MenuState::Closed);
+}
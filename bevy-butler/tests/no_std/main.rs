@@ -0,0 +1,7 @@
+//! Run with `--no-default-features` to check the macros against a `no_std` build of bevy-butler.
+#![no_std]
+
+// The test harness itself still needs std
+extern crate std;
+
+mod no_std;
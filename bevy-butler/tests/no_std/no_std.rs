@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// Every macro here expands without the std prelude, so generated code can't rely on
+// `String`, `Vec` or `std::` paths
+
+#[butler_plugin]
+struct GamePlugin;
+
+#[butler_plugin_group(name = "GamePlugins")]
+struct GamePlugins;
+
+#[add_plugin(to_group = GamePlugins)]
+#[butler_plugin]
+struct InnerPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = GamePlugin)]
+struct Counter(u32);
+
+#[derive(Event)]
+#[add_event(plugin = GamePlugin)]
+struct Ping;
+
+#[derive(Component, Reflect)]
+#[register_type(plugin = GamePlugin)]
+#[register_component(plugin = GamePlugin)]
+struct Marker;
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[insert_state(plugin = GamePlugin)]
+enum GameState {
+    #[default]
+    Running,
+}
+
+#[add_system(plugin = GamePlugin, schedule = Update)]
+fn count(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[add_system(plugin = GamePlugin, schedule = Update, every_frames = 2)]
+fn ping(mut events: EventWriter<Ping>) {
+    events.write(Ping);
+}
+
+#[add_observer(plugin = GamePlugin)]
+fn on_marker(_trigger: Trigger<OnAdd, Marker>) {}
+
+#[spawn(plugin = GamePlugin, schedule = Startup, marker)]
+fn spawn_marker() -> Marker {
+    Marker
+}
+
+butler_chain!(plugin = GamePlugin, schedule = Update, [count, ping]);
+
+#[wasm_bindgen_test(unsupported = test)]
+fn no_std() {
+    let mut app = App::new();
+    app.add_plugins((bevy_state::app::StatesPlugin, GamePlugin, GamePlugins));
+    app.update();
+
+    assert_eq!(<GamePlugins as PluginGroup>::name(), "GamePlugins");
+    assert_eq!(app.world().resource::<Counter>().0, 2);
+    assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Running);
+    assert!(app.is_plugin_added::<InnerPlugin>());
+}
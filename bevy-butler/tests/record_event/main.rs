@@ -0,0 +1,4 @@
+include!("../common.rs");
+
+mod record;
+mod replay_file;
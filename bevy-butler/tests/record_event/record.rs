@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy_butler::{record::*, *};
+use serde::{Deserialize, Serialize};
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Event, Clone, Serialize, Deserialize)]
+#[add_event(plugin = MyPlugin, record, record_capacity = 2)]
+struct Jump(u32);
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct Frame(u32);
+
+#[add_system(plugin = MyPlugin, schedule = Update)]
+fn jump(mut frame: ResMut<Frame>, mut jumps: EventWriter<Jump>) {
+    frame.0 += 1;
+    jumps.write(Jump(frame.0));
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    // Only the last two frames are kept
+    let recorder = app.world().resource::<EventRecorder<Jump>>();
+    let recorded: Vec<u32> = recorder
+        .frames()
+        .flat_map(|f| f.events.iter().map(|e| e.0))
+        .collect();
+    assert_eq!(recorded, [2, 3]);
+}
+
+#[test]
+fn skips_replayed() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+    app.insert_resource(EventReplay::new([RecordedFrame {
+        frame: 0,
+        events: vec![Jump(100)],
+    }]));
+    app.update();
+
+    // Only the jump written by the system is recorded, not the replayed one
+    let recorder = app.world().resource::<EventRecorder<Jump>>();
+    let recorded: Vec<u32> = recorder
+        .frames()
+        .flat_map(|f| f.events.iter().map(|e| e.0))
+        .collect();
+    assert_eq!(recorded, [1]);
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use bevy_butler::{record::*, *};
+use serde::{Deserialize, Serialize};
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct RecordPlugin;
+
+#[butler_plugin]
+struct ReplayPlugin;
+
+#[derive(Event, Clone, Serialize, Deserialize)]
+#[add_event(plugin = RecordPlugin, record)]
+#[add_event(plugin = ReplayPlugin, record)]
+struct Message(String);
+
+#[add_system(plugin = RecordPlugin, schedule = Update)]
+fn send_messages(mut frame: Local<u32>, mut messages: EventWriter<Message>) {
+    *frame += 1;
+    if *frame % 2 == 0 {
+        messages.write(Message(format!("Frame {}", *frame)));
+    }
+}
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = ReplayPlugin)]
+struct Received(Vec<String>);
+
+#[add_system(plugin = ReplayPlugin, schedule = Update)]
+fn receive_messages(mut messages: EventReader<Message>, mut received: ResMut<Received>) {
+    received.0.extend(messages.read().map(|m| m.0.clone()));
+}
+
+fn record_and_replay(extension: &str) {
+    let path = std::env::temp_dir().join(format!(
+        "bevy_butler_replay_{}.{extension}",
+        std::process::id()
+    ));
+
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(RecordPlugin);
+    for _ in 0..4 {
+        app.update();
+    }
+    app.world()
+        .resource::<EventRecorder<Message>>()
+        .save(&path)
+        .unwrap();
+
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(ReplayPlugin);
+    app.insert_resource(EventReplay::<Message>::load(&path).unwrap());
+    for _ in 0..4 {
+        app.update();
+    }
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(app.world().resource::<Received>().0, ["Frame 2", "Frame 4"]);
+    assert!(!app.world().contains_resource::<EventReplay<Message>>());
+    assert_eq!(
+        app.world()
+            .resource::<EventRecorder<Message>>()
+            .frames()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn ron() {
+    record_and_replay("ron");
+}
+
+#[test]
+fn json() {
+    record_and_replay("json");
+}
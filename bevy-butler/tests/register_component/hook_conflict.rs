@@ -0,0 +1,21 @@
+use bevy::ecs::{component::HookContext, world::DeferredWorld};
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[butler_plugin]
+struct MyPlugin;
+
+fn derive_hook(_world: DeferredWorld, _ctx: HookContext) {}
+
+fn butler_hook(_world: DeferredWorld, _ctx: HookContext) {}
+
+#[derive(Component)]
+#[component(on_add = derive_hook)]
+#[register_component(plugin = MyPlugin, on_add = butler_hook)]
+struct Player;
+
+#[test]
+#[should_panic(expected = "already has an `on_add` hook")]
+fn test() {
+    App::new().add_plugins(MyPlugin);
+}
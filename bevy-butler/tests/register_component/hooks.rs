@@ -0,0 +1,42 @@
+use bevy::ecs::{component::HookContext, world::DeferredWorld};
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugin = MyPlugin)]
+struct HookCounter {
+    added: u8,
+    removed: u8,
+}
+
+fn player_added(mut world: DeferredWorld, _ctx: HookContext) {
+    world.resource_mut::<HookCounter>().added += 1;
+}
+
+fn player_removed(mut world: DeferredWorld, _ctx: HookContext) {
+    world.resource_mut::<HookCounter>().removed += 1;
+}
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin, on_add = player_added, on_remove = player_removed)]
+struct Player;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let world = app.world_mut();
+    let entity = world.spawn(Player).id();
+    world.entity_mut(entity).remove::<Player>();
+
+    let counter = world.resource::<HookCounter>();
+    assert_eq!(counter.added, 1);
+    assert_eq!(counter.removed, 1);
+}
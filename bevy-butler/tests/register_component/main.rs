@@ -0,0 +1,7 @@
+include!("../common.rs");
+
+mod hook_conflict;
+mod hooks;
+mod reflect_component;
+mod register_component;
+mod requires;
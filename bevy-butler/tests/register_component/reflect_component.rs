@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component, Reflect)]
+#[register_component(plugin = MyPlugin, reflect)]
+struct Velocity(f32, f32);
+
+#[add_system(plugin = MyPlugin, schedule = Startup)]
+fn read_type_registration(registry: Res<AppTypeRegistry>) {
+    let registry = registry.read();
+    let registration = registry
+        .get_with_short_type_path("Velocity")
+        .expect("Velocity was not registered to the type registry");
+
+    assert!(registration.data::<ReflectComponent>().is_some());
+}
+
+#[test]
+fn test() {
+    App::new()
+        .add_plugins(log_plugin())
+        .add_plugins(MyPlugin)
+        .run();
+}
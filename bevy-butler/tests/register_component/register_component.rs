@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin)]
+struct Health(#[allow(dead_code)] u32);
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    assert!(app.world().component_id::<Health>().is_some());
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Component, Default)]
+struct Health(u32);
+
+#[derive(Component)]
+struct Mana(u32);
+
+impl Default for Mana {
+    fn default() -> Self {
+        Mana(50)
+    }
+}
+
+#[derive(Component)]
+#[register_component(plugin = MyPlugin, requires = [Health, Mana])]
+struct Player;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let entity = app.world_mut().spawn(Player).id();
+    let entity = app.world().entity(entity);
+    assert_eq!(entity.get::<Health>().map(|h| h.0), Some(0));
+    assert_eq!(entity.get::<Mana>().map(|m| m.0), Some(50));
+}
@@ -0,0 +1,52 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_butler::*;
+use serde::Deserialize;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+struct Ron;
+struct Json;
+struct Toml;
+
+#[derive(Resource, Deserialize)]
+#[insert_resource(plugin = MyPlugin, generics = <Ron>, from_file = "tests/resource_file/config/physics.ron")]
+#[insert_resource(plugin = MyPlugin, generics = <Json>, from_file = "tests/resource_file/config/physics.json")]
+#[insert_resource(plugin = MyPlugin, generics = <Toml>, from_file = "tests/resource_file/config/physics.toml")]
+struct Physics<F: Send + Sync + 'static> {
+    gravity: f32,
+    substeps: u8,
+    #[serde(skip)]
+    _format: PhantomData<F>,
+}
+
+impl<F: Send + Sync + 'static> Default for Physics<F> {
+    fn default() -> Self {
+        Self {
+            gravity: 9.8,
+            substeps: 1,
+            _format: PhantomData,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    let world = app.world();
+
+    let ron = world.resource::<Physics<Ron>>();
+    assert_eq!((ron.gravity, ron.substeps), (12.5, 4));
+
+    let json = world.resource::<Physics<Json>>();
+    assert_eq!((json.gravity, json.substeps), (3.7, 2));
+
+    let toml = world.resource::<Physics<Toml>>();
+    assert_eq!((toml.gravity, toml.substeps), (1.6, 8));
+}
@@ -0,0 +1,6 @@
+include!("../common.rs");
+
+mod from_file;
+mod malformed;
+mod missing_file;
+mod persist;
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use serde::Deserialize;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Deserialize, Default)]
+#[insert_resource(plugin = MyPlugin, from_file = "tests/resource_file/config/malformed.ron")]
+struct Physics {
+    #[allow(dead_code)]
+    gravity: f32,
+}
+
+#[test]
+#[should_panic(
+    expected = "Failed to load resource_file::malformed::Physics from tests/resource_file/config/malformed.ron"
+)]
+fn test() {
+    App::new().add_plugins(MyPlugin);
+}
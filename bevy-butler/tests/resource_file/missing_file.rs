@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use serde::Deserialize;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Deserialize, Default)]
+#[insert_resource(plugin = MyPlugin, from_file = "tests/resource_file/config/missing.ron")]
+struct Physics {
+    gravity: f32,
+}
+
+#[derive(Resource, Deserialize)]
+#[insert_resource(
+    plugin = MyPlugin,
+    from_file = "tests/resource_file/config/missing.toml",
+    init = Audio { volume: 0.5 },
+)]
+struct Audio {
+    volume: f32,
+}
+
+#[test]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(MyPlugin);
+
+    assert_eq!(app.world().resource::<Physics>().gravity, 0.0);
+    assert_eq!(app.world().resource::<Audio>().volume, 0.5);
+}
@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_butler::*;
+use serde::{Deserialize, Serialize};
+
+use crate::common::{capture_logs, log_plugin};
+
+const EXIT_PATH: &str = "target/butler-tests/persist_exit.ron";
+const DEBOUNCE_PATH: &str = "target/butler-tests/persist_debounce.json";
+const SKIPPED_PATH: &str = "target/butler-tests/persist_skipped.toml";
+const LATE_EXIT_PATH: &str = "target/butler-tests/persist_late_exit.ron";
+const OUTDATED_PATH: &str = "target/butler-tests/persist_outdated.ron";
+const CORRUPT_PATH: &str = "target/butler-tests/persist_corrupt.json";
+
+// Separate plugins, so the two tests don't save each other's files
+#[butler_plugin]
+struct ExitPlugin;
+
+#[butler_plugin]
+struct DebouncePlugin;
+
+#[butler_plugin]
+struct SkippedPlugin;
+
+#[butler_plugin]
+struct LateExitPlugin;
+
+#[butler_plugin]
+struct OutdatedPlugin;
+
+#[butler_plugin]
+struct CorruptPlugin;
+
+#[derive(Resource, Serialize, Deserialize, Default, PartialEq, Debug)]
+#[insert_resource(plugin = ExitPlugin, persist = "target/butler-tests/persist_exit.ron")]
+struct ExitSettings {
+    volume: f32,
+    fullscreen: bool,
+}
+
+#[derive(Resource, Serialize, Deserialize, Default, PartialEq, Debug)]
+#[insert_resource(
+    plugin = DebouncePlugin,
+    persist = "target/butler-tests/persist_debounce.json",
+    persist_debounce = Duration::ZERO,
+)]
+struct DebounceSettings {
+    sensitivity: f32,
+}
+
+#[derive(Resource, Serialize, Deserialize, PartialEq, Debug)]
+#[insert_resource(
+    plugin = SkippedPlugin,
+    init_with = |_| Err::<Profile, _>("no profile selected"),
+    on_init_error = skip,
+    persist = "target/butler-tests/persist_skipped.toml",
+    persist_debounce = Duration::ZERO,
+)]
+struct Profile {
+    name: String,
+}
+
+#[derive(Resource, Serialize, Deserialize, Default, PartialEq, Debug)]
+#[insert_resource(plugin = LateExitPlugin, persist = "target/butler-tests/persist_late_exit.ron")]
+struct Keybinds {
+    jump: String,
+}
+
+#[derive(Resource, Serialize, Deserialize, PartialEq, Debug)]
+#[insert_resource(
+    plugin = OutdatedPlugin,
+    init = Graphics { quality: 2, vsync: true },
+    persist = "target/butler-tests/persist_outdated.ron",
+)]
+struct Graphics {
+    quality: u8,
+    vsync: bool,
+}
+
+#[derive(Resource, Serialize, Deserialize, Default, PartialEq, Debug)]
+#[insert_resource(plugin = CorruptPlugin, persist = "target/butler-tests/persist_corrupt.json")]
+struct Progress {
+    level: u32,
+}
+
+// Exits from `Last`, which the app stops after, so the resource has to be saved after it
+#[add_system(plugin = LateExitPlugin, schedule = Last)]
+fn exit(mut keybinds: ResMut<Keybinds>, mut exit: EventWriter<AppExit>) {
+    keybinds.jump = "Space".to_string();
+    exit.write(AppExit::Success);
+}
+
+fn app(plugin: impl Plugin) -> App {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(plugin);
+    app
+}
+
+#[test]
+fn save_on_exit() {
+    std::fs::remove_file(EXIT_PATH).ok();
+
+    let mut app = app(ExitPlugin);
+    assert_eq!(
+        *app.world().resource::<ExitSettings>(),
+        ExitSettings::default()
+    );
+
+    app.world_mut().resource_mut::<ExitSettings>().volume = 0.5;
+    app.update();
+    assert!(
+        std::fs::metadata(EXIT_PATH).is_err(),
+        "Settings were saved before exiting"
+    );
+
+    app.world_mut().send_event(AppExit::Success);
+    app.update();
+
+    let app = self::app(ExitPlugin);
+    assert_eq!(
+        *app.world().resource::<ExitSettings>(),
+        ExitSettings {
+            volume: 0.5,
+            fullscreen: false,
+        }
+    );
+}
+
+#[test]
+fn save_on_change() {
+    std::fs::remove_file(DEBOUNCE_PATH).ok();
+
+    let mut app = app(DebouncePlugin);
+    app.update();
+    assert!(
+        std::fs::metadata(DEBOUNCE_PATH).is_err(),
+        "Settings were saved without being changed"
+    );
+
+    app.world_mut()
+        .resource_mut::<DebounceSettings>()
+        .sensitivity = 2.0;
+    app.update();
+
+    let app = self::app(DebouncePlugin);
+    assert_eq!(app.world().resource::<DebounceSettings>().sensitivity, 2.0);
+}
+
+#[test]
+fn skipped_init() {
+    std::fs::remove_file(SKIPPED_PATH).ok();
+
+    let mut app = app(SkippedPlugin);
+    assert!(!app.world().contains_resource::<Profile>());
+
+    app.update();
+    app.world_mut().send_event(AppExit::Success);
+    app.update();
+    assert!(
+        std::fs::metadata(SKIPPED_PATH).is_err(),
+        "A missing resource was saved"
+    );
+}
+
+#[test]
+fn save_on_late_exit() {
+    std::fs::remove_file(LATE_EXIT_PATH).ok();
+
+    let mut app = app(LateExitPlugin);
+    app.update();
+    assert!(app.should_exit().is_some());
+
+    let app = self::app(LateExitPlugin);
+    assert_eq!(app.world().resource::<Keybinds>().jump, "Space");
+}
+
+/// Writes `contents` to `path`, as if it was saved by an older or interrupted build.
+fn write_file(path: &str, contents: &str) {
+    std::fs::create_dir_all("target/butler-tests").unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn outdated_file() {
+    // Saved before `vsync` was added
+    write_file(OUTDATED_PATH, "(quality: 1)");
+
+    let mut app = app(OutdatedPlugin);
+    assert_eq!(
+        *app.world().resource::<Graphics>(),
+        Graphics {
+            quality: 2,
+            vsync: true,
+        }
+    );
+
+    app.world_mut().send_event(AppExit::Success);
+    app.update();
+
+    // The file is replaced on the next save
+    let app = self::app(OutdatedPlugin);
+    assert_eq!(
+        *app.world().resource::<Graphics>(),
+        Graphics {
+            quality: 2,
+            vsync: true,
+        }
+    );
+    assert!(std::fs::read_to_string(OUTDATED_PATH).unwrap().contains("vsync"));
+}
+
+#[test]
+fn corrupt_file() {
+    write_file(CORRUPT_PATH, "{\"level\": 3");
+
+    let logs = capture_logs(|| {
+        let app = app(CorruptPlugin);
+        assert_eq!(*app.world().resource::<Progress>(), Progress::default());
+    });
+    assert!(
+        logs.iter().any(|log| log.starts_with("ERROR") && log.contains("Progress")),
+        "{logs:?}"
+    );
+}
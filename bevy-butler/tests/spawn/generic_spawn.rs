@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct LevelPlugin;
+
+#[derive(Component, Default)]
+struct Health<T: Send + Sync + 'static>(u32, std::marker::PhantomData<T>);
+
+struct Player;
+struct Enemy;
+
+#[derive(Resource)]
+struct EnemiesEnabled;
+
+#[spawn(plugin = LevelPlugin, schedule = Startup, generics = <Player>)]
+#[spawn(plugin = LevelPlugin, schedule = Startup, generics = <Enemy>, run_if = resource_exists::<EnemiesEnabled>)]
+fn spawn_health<T: Send + Sync + 'static>() -> Health<T> {
+    Health(100, default())
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin()).add_plugins(LevelPlugin);
+    app.update();
+
+    let world = app.world_mut();
+    let health = world
+        .query::<&Health<Player>>()
+        .single(world)
+        .expect("Player health was not spawned");
+    assert_eq!(health.0, 100);
+    assert_eq!(world.query::<&Health<Enemy>>().iter(world).count(), 0);
+}
@@ -0,0 +1,5 @@
+include!("../common.rs");
+
+mod generic_spawn;
+mod spawn;
+mod spawn_vec;
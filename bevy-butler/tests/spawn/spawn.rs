@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct LevelPlugin;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Resource)]
+struct SpawnPoint(Vec3);
+
+#[spawn(plugin = LevelPlugin, schedule = Startup, name, marker)]
+fn player(spawn_point: Res<SpawnPoint>) -> impl Bundle {
+    (Player, Transform::from_translation(spawn_point.0))
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .insert_resource(SpawnPoint(Vec3::new(1.0, 2.0, 3.0)))
+        .add_plugins(LevelPlugin);
+    app.update();
+
+    let world = app.world_mut();
+    let (name, transform) = world
+        .query_filtered::<(&Name, &Transform), (With<Player>, With<ButlerSpawned<LevelPlugin>>)>()
+        .single(world)
+        .expect("Player was not spawned");
+    assert_eq!(name.as_str(), "player");
+    assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+}
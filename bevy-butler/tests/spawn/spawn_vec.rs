@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use crate::common::log_plugin;
+
+#[butler_plugin]
+struct LevelPlugin;
+
+#[derive(Component)]
+struct Wall;
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[insert_state(plugin = LevelPlugin)]
+enum LevelState {
+    #[default]
+    Loading,
+    Playing,
+}
+
+#[spawn(plugin = LevelPlugin, schedule = OnEnter(LevelState::Playing), marker)]
+fn walls() -> Vec<(Wall, Transform)> {
+    (0..4)
+        .map(|i| (Wall, Transform::from_xyz(i as f32, 0.0, 0.0)))
+        .collect()
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test() {
+    let mut app = App::new();
+    app.add_plugins(log_plugin())
+        .add_plugins(bevy_state::app::StatesPlugin)
+        .add_plugins(LevelPlugin);
+    app.update();
+
+    let world = app.world_mut();
+    assert!(ButlerSpawned::<LevelPlugin>::entities(world).is_empty());
+
+    world
+        .resource_mut::<NextState<LevelState>>()
+        .set(LevelState::Playing);
+    app.update();
+
+    let world = app.world_mut();
+    assert_eq!(ButlerSpawned::<LevelPlugin>::entities(world).len(), 4);
+    assert_eq!(world.query::<&Wall>().iter(world).count(), 4);
+
+    ButlerSpawned::<LevelPlugin>::despawn_all(world);
+    assert_eq!(world.query::<&Wall>().iter(world).count(), 0);
+}
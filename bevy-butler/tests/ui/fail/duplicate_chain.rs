@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[butler_plugin]
+struct MyPlugin;
+
+fn first() {}
+
+fn second() {}
+
+butler_chain!(plugin = MyPlugin, schedule = Update, [first, second]);
+
+butler_chain!(plugin = MyPlugin, schedule = Update, [first, second]);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use bevy_butler::*;
+
+#[butler_plugin]
+struct MyPlugin;
+
+fn first() {}
+
+fn second() {}
+
+butler_chain!(plugin = MyPlugin, schedle = Update, [first, second]);
+
+fn main() {}
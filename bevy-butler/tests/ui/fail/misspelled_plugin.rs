@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[derive(Resource, Default)]
+#[insert_resource(plugn = MyPlugin)]
+struct Counter(u32);
+
+#[add_observer(plugn = MyPlugin)]
+fn observer(_trigger: Trigger<OnAdd, Name>) {}
+
+fn main() {
+    App::new().insert_resource(Counter(0)).add_observer(observer);
+}
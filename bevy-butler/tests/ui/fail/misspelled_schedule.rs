@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+#[butler_plugin]
+struct MyPlugin;
+
+#[add_system(plugin = MyPlugin, schdule = Update)]
+fn system() {}
+
+fn main() {
+    App::new().add_systems(Update, system);
+}
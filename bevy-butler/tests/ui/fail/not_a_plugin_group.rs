@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_butler::*;
+
+struct NotAPluginGroup;
+
+#[butler_plugin]
+#[add_plugin(to_group = NotAPluginGroup)]
+struct MyPlugin;
+
+fn main() {
+    App::new().add_plugins(MyPlugin);
+}
@@ -0,0 +1,8 @@
+//! Checks the errors reported by butler macros, and that the annotated items are still emitted
+//! alongside them so the rest of the crate resolves.
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn ui() {
+    trybuild::TestCases::new().compile_fail("tests/ui/fail/*.rs");
+}
@@ -885,6 +885,19 @@ pub fn flag_disallowed_error(span: Span) -> Error {
     syn::Error::new(span, "unexpected flag, expected `=` or parentheses")
 }
 
+/// Returns the field in `fields` that `path` is most likely a typo of.
+///
+/// A field is only a match if it's within a third of its length in edits of `path`, counting a
+/// swap of two adjacent characters as one edit.
+pub fn closest_field<'f>(path: &str, fields: &[&'f str]) -> Option<&'f str> {
+    fields
+        .iter()
+        .map(|field| (strsim::osa_distance(path, field), *field))
+        .filter(|(distance, field)| *distance <= (field.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, field)| field)
+}
+
 /// Returns an error with an "unknown field" message.
 ///
 /// `path` is the name of the unknown field that will be included in the error message. The names
@@ -892,14 +905,7 @@ pub fn flag_disallowed_error(span: Span) -> Error {
 /// then a "did you mean" message will be appended to the end of the error message. The error will
 /// be spanned to `span`.
 pub fn unknown_error(path: &str, span: Span, fields: &[&str]) -> Error {
-    let mut closest = None;
-    for field in fields {
-        let sim = strsim::jaro_winkler(path, field);
-        if sim > 0.8 && closest.as_ref().map(|(s, _)| sim > *s).unwrap_or(true) {
-            closest = Some((sim, *field));
-        }
-    }
-    if let Some((_, closest)) = closest {
+    if let Some(closest) = closest_field(path, fields) {
         Error::new(
             span,
             format!("unknown field `{path}`, did you mean `{closest}`?"),